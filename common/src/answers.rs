@@ -0,0 +1,238 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::solution::{Part, Report};
+
+/// File that recorded answers are read from when `--answers` isn't given.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.json";
+
+/// Command-line flags controlling answer verification.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct AnswerArgs {
+    /// File of known answers to check results against [default: answers.json].
+    #[arg(long, value_name = "PATH")]
+    pub answers: Option<PathBuf>,
+
+    /// Save answers that aren't in the answers file yet.
+    #[arg(long)]
+    pub record: bool,
+}
+
+impl AnswerArgs {
+    pub fn path(&self) -> PathBuf {
+        self.answers
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE))
+    }
+}
+
+/// Stable fingerprint of a puzzle input, so that answers for different
+/// inputs to the same day can be told apart. This is 64-bit FNV-1a, which
+/// unlike `DefaultHasher` is guaranteed not to change between builds.
+pub fn input_hash(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325_u64, |h, b| {
+        (h ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Self::New => write!(f, "new"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Key {
+    day: u32,
+    part: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    day: u32,
+    part: u32,
+    input: String,
+    answer: String,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct File {
+    answers: Vec<Entry>,
+}
+
+/// Known-good answers, keyed by day, part and input hash.
+#[derive(Debug, Clone, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<(Key, String), String>,
+}
+
+impl AnswerStore {
+    /// Read answers from `path`. A missing file is treated as empty.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers file {}", path.display()))?;
+        let file: File = serde_json::from_str(&text)
+            .with_context(|| format!("failed to parse answers file {}", path.display()))?;
+
+        let answers = file
+            .answers
+            .into_iter()
+            .map(|e| {
+                let key = Key {
+                    day: e.day,
+                    part: e.part,
+                };
+                ((key, e.input), e.answer)
+            })
+            .collect();
+        Ok(Self { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = File {
+            answers: self
+                .answers
+                .iter()
+                .map(|((key, input), answer)| Entry {
+                    day: key.day,
+                    part: key.part,
+                    input: input.clone(),
+                    answer: answer.clone(),
+                })
+                .collect(),
+        };
+        let mut text = serde_json::to_string_pretty(&file)?;
+        text.push('\n');
+        std::fs::write(path, text)
+            .with_context(|| format!("failed to write answers file {}", path.display()))
+    }
+
+    pub fn get(&self, day: u32, part: Part, input_hash: &str) -> Option<&str> {
+        let key = Key {
+            day,
+            part: part.number(),
+        };
+        self.answers
+            .get(&(key, input_hash.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, part: Part, input_hash: &str, answer: &str) -> Verdict {
+        match self.get(day, part, input_hash) {
+            None => Verdict::New,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    /// Record `answer`, returning whether it was previously unknown. Existing
+    /// answers are never overwritten.
+    pub fn record(&mut self, day: u32, part: Part, input_hash: &str, answer: &str) -> bool {
+        let key = Key {
+            day,
+            part: part.number(),
+        };
+        let entry = (key, input_hash.to_string());
+        if self.answers.contains_key(&entry) {
+            return false;
+        }
+        self.answers.insert(entry, answer.to_string());
+        true
+    }
+
+    /// Check every outcome in `report`, which was computed from an input with
+    /// the given hash.
+    pub fn check_report(&self, report: &Report, input_hash: &str) -> Vec<(Part, Verdict)> {
+        report
+            .outcomes
+            .iter()
+            .map(|o| {
+                let verdict = self.check(report.day, o.part, input_hash, &o.answer);
+                (o.part, verdict)
+            })
+            .collect()
+    }
+
+    /// Record every new outcome in `report`, returning how many were added.
+    pub fn record_report(&mut self, report: &Report, input_hash: &str) -> usize {
+        report
+            .outcomes
+            .iter()
+            .filter(|o| self.record(report.day, o.part, input_hash, &o.answer))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{input_hash, AnswerStore, Verdict};
+    use crate::solution::Part;
+
+    #[test]
+    fn test_verdicts() -> anyhow::Result<()> {
+        let hash = input_hash("1 2 3\n");
+        assert_eq!(hash, input_hash("1 2 3\n"));
+        assert_ne!(hash, input_hash("1 2 3"));
+
+        let mut store = AnswerStore::default();
+        assert_eq!(store.check(1, Part::One, &hash, "6"), Verdict::New);
+        assert!(store.record(1, Part::One, &hash, "6"));
+        assert!(!store.record(1, Part::One, &hash, "7"));
+
+        assert_eq!(store.check(1, Part::One, &hash, "6"), Verdict::Correct);
+        assert_eq!(
+            store.check(1, Part::One, &hash, "7"),
+            Verdict::Wrong {
+                expected: "6".into()
+            }
+        );
+        assert_eq!(store.check(1, Part::Two, &hash, "6"), Verdict::New);
+        assert_eq!(
+            store.check(1, Part::One, &input_hash(""), "6"),
+            Verdict::New
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_and_load() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.json", std::process::id()));
+        assert!(AnswerStore::load(&path)?.get(1, Part::One, "x").is_none());
+
+        let mut store = AnswerStore::default();
+        store.record(3, Part::Two, "abc", "48");
+        store.save(&path)?;
+
+        let loaded = AnswerStore::load(&path)?;
+        assert_eq!(loaded.get(3, Part::Two, "abc"), Some("48"));
+
+        std::fs::write(&path, "not json")?;
+        assert!(AnswerStore::load(&path).is_err());
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
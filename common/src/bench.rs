@@ -0,0 +1,234 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Solution;
+
+/// Command-line flags controlling benchmark mode.
+#[derive(clap::Args, Debug, Clone)]
+pub struct BenchArgs {
+    /// Time parsing and each part repeatedly instead of solving once.
+    #[arg(long)]
+    pub bench: bool,
+
+    /// Untimed runs before measuring.
+    #[arg(long, default_value_t = 3, requires = "bench")]
+    pub warmup: usize,
+
+    /// Timed runs per stage.
+    #[arg(long, default_value_t = 20, requires = "bench")]
+    pub iterations: usize,
+
+    /// Compare against results previously saved with --save-baseline.
+    #[arg(long, value_name = "PATH", requires = "bench")]
+    pub baseline: Option<PathBuf>,
+
+    /// Save results so later runs can be compared against them.
+    #[arg(long, value_name = "PATH", requires = "bench")]
+    pub save_baseline: Option<PathBuf>,
+}
+
+/// Which step of solving a day a measurement is for.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part 1"),
+            Self::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Summary statistics over a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            samples: n,
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings for every stage of one day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u32,
+    pub stages: BTreeMap<Stage, Stats>,
+}
+
+fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Time parsing and both parts of `S` against `input`. Each part is timed
+/// against a single parsed input.
+pub fn bench<S: Solution>(
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> anyhow::Result<DayBench> {
+    // Solve once up front so that errors are reported rather than timed.
+    let parsed = S::parse(input)?;
+    S::part1(&parsed)?;
+    S::part2(&parsed)?;
+
+    let stages = BTreeMap::from([
+        (
+            Stage::Parse,
+            measure(warmup, iterations, || S::parse(input)),
+        ),
+        (
+            Stage::Part1,
+            measure(warmup, iterations, || S::part1(&parsed)),
+        ),
+        (
+            Stage::Part2,
+            measure(warmup, iterations, || S::part2(&parsed)),
+        ),
+    ]);
+
+    Ok(DayBench {
+        day: S::DAY,
+        stages,
+    })
+}
+
+/// A set of saved benchmark results.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: Vec<DayBench>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read baseline {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("failed to parse baseline {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut text = serde_json::to_string_pretty(self)?;
+        text.push('\n');
+        std::fs::write(path, text)
+            .with_context(|| format!("failed to write baseline {}", path.display()))
+    }
+
+    pub fn get(&self, day: u32, stage: Stage) -> Option<&Stats> {
+        self.days
+            .iter()
+            .find(|d| d.day == day)
+            .and_then(|d| d.stages.get(&stage))
+    }
+}
+
+/// Relative change in median time from `before` to `after`, as a percentage.
+/// `None` if `before` is too fast to have been measured at all.
+pub fn change_percent(before: &Stats, after: &Stats) -> Option<f64> {
+    let before = before.median.as_secs_f64();
+    let after = after.median.as_secs_f64();
+    (before > 0.0).then(|| (after - before) / before * 100.0)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{change_percent, Baseline, DayBench, Stage, Stats};
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(9), ms(5)]);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        // Sample variance is (1 + 9 + 16 + 0) / 3.
+        let expected = (26.0_f64 / 3.0).sqrt() / 1000.0;
+        assert!((stats.stddev.as_secs_f64() - expected).abs() < 1e-9);
+
+        let single = Stats::from_samples(&[ms(7)]);
+        assert_eq!((single.median, single.stddev), (ms(7), Duration::ZERO));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() -> anyhow::Result<()> {
+        let before = Stats::from_samples(&[ms(10)]);
+        let after = Stats::from_samples(&[ms(15)]);
+        assert!((change_percent(&before, &after).unwrap() - 50.0).abs() < 1e-9);
+        let instant = Stats::from_samples(&[Duration::ZERO]);
+        assert_eq!(change_percent(&instant, &after), None);
+
+        let baseline = Baseline {
+            days: vec![DayBench {
+                day: 6,
+                stages: [(Stage::Part2, before)].into(),
+            }],
+        };
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        baseline.save(&path)?;
+        let loaded = Baseline::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(loaded.get(6, Stage::Part2), Some(&before));
+        assert_eq!(loaded.get(6, Stage::Part1), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Coord3(pub i32, pub i32, pub i32);
+
+impl Coord3 {
+    pub fn offset(self, delta: Delta3) -> Self {
+        self + delta
+    }
+
+    pub fn manhattan_distance(self, other: Coord3) -> u32 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1) + self.2.abs_diff(other.2)
+    }
+
+    /// The cells sharing a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        Delta3::face_directions().map(move |d| self + d)
+    }
+
+    /// The cells sharing a face, edge or corner with this one.
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        Delta3::directions().map(move |d| self + d)
+    }
+}
+
+impl std::ops::Add<Delta3> for Coord3 {
+    type Output = Coord3;
+
+    fn add(self, rhs: Delta3) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl std::ops::Sub<Delta3> for Coord3 {
+    type Output = Coord3;
+
+    fn sub(self, rhs: Delta3) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+/// `a - b` is the step that takes `a` to `b`, as for `Coord`.
+impl std::ops::Sub<Coord3> for Coord3 {
+    type Output = Delta3;
+
+    fn sub(self, rhs: Coord3) -> Self::Output {
+        Delta3(rhs.0 - self.0, rhs.1 - self.1, rhs.2 - self.2)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Delta3(pub i32, pub i32, pub i32);
+
+impl std::ops::Mul<i32> for Delta3 {
+    type Output = Self;
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl std::ops::Neg for Delta3 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+impl Delta3 {
+    pub fn invert(self) -> Self {
+        -self
+    }
+
+    /// Unit steps along a single axis.
+    pub fn face_directions() -> impl Iterator<Item = Self> {
+        const ALL: [Delta3; 6] = [
+            Delta3(-1, 0, 0),
+            Delta3(1, 0, 0),
+            Delta3(0, -1, 0),
+            Delta3(0, 1, 0),
+            Delta3(0, 0, -1),
+            Delta3(0, 0, 1),
+        ];
+        ALL.iter().copied()
+    }
+
+    /// Every non-zero step with each component in `-1..=1`.
+    pub fn directions() -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Delta3(x, y, z))))
+            .filter(|&d| d != Delta3(0, 0, 0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Coord3, Delta3};
+
+    #[test]
+    fn test_neighbors() {
+        let c = Coord3(1, -2, 3);
+        assert_eq!(c.neighbors6().count(), 6);
+        assert!(c.neighbors6().all(|n| n.manhattan_distance(c) == 1));
+
+        let all: Vec<Coord3> = c.neighbors26().collect();
+        assert_eq!(all.len(), 26);
+        assert!(all.contains(&Coord3(0, -3, 2)));
+        assert!(!all.contains(&c));
+
+        let d = c - Coord3(4, 0, 3);
+        assert_eq!(d, Delta3(3, 2, 0));
+        assert_eq!(c + d * 2 - d, Coord3(4, 0, 3));
+    }
+}
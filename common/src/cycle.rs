@@ -0,0 +1,189 @@
+//! Cycle detection for sequences `x0, f(x0), f(f(x0)), ...` over a finite
+//! state space, for puzzles that ask for the state after far more steps than
+//! could be simulated directly.
+//!
+//! Every finder here loops forever if the sequence never repeats.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of an eventually periodic sequence: `prefix` states that are
+/// never revisited, followed by a loop of `length` states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest index whose state equals the state at index `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. Uses constant memory, but calls `step` about
+/// three times as often as [`hashed`].
+pub fn floyd<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Brent's algorithm. Uses constant memory, and usually fewer steps than
+/// [`floyd`].
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Find the cycle by remembering every state seen. Calls `step` exactly
+/// `prefix + length` times, and returns those states in order, so that the
+/// state at any index can be looked up with [`Cycle::reduce`].
+pub fn hashed<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = start;
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            let length = history.len() - prefix;
+            return (Cycle { prefix, length }, history);
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+}
+
+/// The state after `n` steps from `start`, skipping ahead once the sequence
+/// starts repeating.
+pub fn nth_state<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = start;
+    while history.len() < n {
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle {
+                prefix,
+                length: history.len() - prefix,
+            };
+            return history.swap_remove(cycle.reduce(n));
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::{brent, floyd, hashed, nth_state, Cycle};
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> ... -> 9 -> 4
+    fn step(x: &u32) -> u32 {
+        if *x < 9 {
+            x + 1
+        } else {
+            4
+        }
+    }
+
+    #[test]
+    fn test_finders_agree() {
+        let expected = Cycle {
+            prefix: 4,
+            length: 6,
+        };
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+
+        let (cycle, history) = hashed(0, step);
+        assert_eq!(cycle, expected);
+        assert_eq!(history, (0..10).collect::<Vec<_>>());
+
+        // A pure loop has no prefix; a fixed point is a loop of one.
+        let pure = Cycle {
+            prefix: 0,
+            length: 6,
+        };
+        assert_eq!(floyd(5, step), pure);
+        assert_eq!(brent(5, step), pure);
+        let fixed = Cycle {
+            prefix: 0,
+            length: 1,
+        };
+        assert_eq!(floyd(7, |&x: &u32| x), fixed);
+        assert_eq!(brent(7, |&x: &u32| x), fixed);
+        assert_eq!(hashed(7, |&x: &u32| x).0, fixed);
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let mut x = 0;
+        for n in 0..40 {
+            assert_eq!(nth_state(0, step, n), x, "n = {n}");
+            x = step(&x);
+        }
+        // 1_000_000_001 - 4 is one more than a multiple of 6.
+        assert_eq!(nth_state(0, step, 1_000_000_001), 5);
+
+        let (cycle, history) = hashed(0, step);
+        assert_eq!(
+            history[cycle.reduce(1_000_000_000)],
+            nth_state(0, step, 1_000_000_000)
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Worked examples declared in a day's README, so that each example and its
+//! expected answers are written down once and checked by generated tests.
+//!
+//! An example is a fenced block whose info string is `example`, followed by
+//! the expected answer for each part it covers:
+//!
+//! ````markdown
+//! ```example part1=11 part2=31
+//! 3   4
+//! 4   3
+//! ```
+//! ````
+//!
+//! A README may hold any number of examples, and an example may cover only
+//! one part (as when part two of a puzzle comes with its own example).
+
+use crate::solution::{run, Part, Solution};
+
+const FENCE: &str = "```";
+const INFO: &str = "example";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// 1-based line of the opening fence, for error messages.
+    pub line: usize,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Extract every `example` block from `markdown`. Other fenced blocks are
+/// ignored.
+pub fn parse(markdown: &str) -> anyhow::Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    let mut lines = markdown.lines().enumerate();
+
+    while let Some((i, line)) = lines.next() {
+        let Some(info) = line.strip_prefix(FENCE) else {
+            continue;
+        };
+        let mut words = info.split_whitespace();
+        if words.next() != Some(INFO) {
+            // Skip over the body of unrelated blocks so that their contents
+            // aren't mistaken for fences.
+            for (_, line) in lines.by_ref() {
+                if line.trim_end() == FENCE {
+                    break;
+                }
+            }
+            continue;
+        }
+
+        let mut example = Example {
+            line: i + 1,
+            input: String::new(),
+            part1: None,
+            part2: None,
+        };
+        for attr in words {
+            let Some((key, value)) = attr.split_once('=') else {
+                anyhow::bail!("line {}: expected key=value, found {attr:?}", i + 1);
+            };
+            match key {
+                "part1" => example.part1 = Some(value.to_string()),
+                "part2" => example.part2 = Some(value.to_string()),
+                _ => anyhow::bail!("line {}: unknown example attribute {key:?}", i + 1),
+            }
+        }
+
+        let mut closed = false;
+        for (_, line) in lines.by_ref() {
+            if line.trim_end() == FENCE {
+                closed = true;
+                break;
+            }
+            example.input.push_str(line);
+            example.input.push('\n');
+        }
+        if !closed {
+            anyhow::bail!("line {}: unterminated example block", i + 1);
+        }
+        examples.push(example);
+    }
+
+    Ok(examples)
+}
+
+/// Solve every example in `markdown` that has an expected answer for `part`,
+/// failing on the first mismatch. It's an error for no example to have an
+/// answer for `part`, so that a typo in the README can't leave a part
+/// untested.
+pub fn check<S: Solution>(markdown: &str, part: Part) -> anyhow::Result<()> {
+    let examples = parse(markdown)?;
+    let mut checked = 0;
+    for example in &examples {
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+        let report = run::<S>(&example.input, &[part])?;
+        let answer = &report.outcomes[0].answer;
+        if answer != expected {
+            anyhow::bail!(
+                "example on line {}, part {}: expected {expected}, got {answer}",
+                example.line,
+                part.number()
+            );
+        }
+        checked += 1;
+    }
+    if checked == 0 {
+        anyhow::bail!("no examples with an answer for part {}", part.number());
+    }
+    Ok(())
+}
+
+/// Generate `test_p1` and `test_p2` for a day from the examples in its
+/// README, e.g. `common::example_tests!(Day5, include_str!("../README.md"));`
+///
+/// Parts whose answers aren't in the README yet can be listed as pending,
+/// which generates their tests as `#[ignore]`d:
+/// `common::example_tests!(Day9, include_str!("../README.md"), pending: p1, p2);`
+#[macro_export]
+macro_rules! example_tests {
+    ($day:ident, $readme:expr) => {
+        $crate::example_tests!(@tests $day, $readme, [], []);
+    };
+    ($day:ident, $readme:expr, pending: p1, p2) => {
+        $crate::example_tests!(
+            @tests $day,
+            $readme,
+            [ignore = "no example answer for part 1 yet"],
+            [ignore = "no example answer for part 2 yet"]
+        );
+    };
+    ($day:ident, $readme:expr, pending: p1) => {
+        $crate::example_tests!(
+            @tests $day,
+            $readme,
+            [ignore = "no example answer for part 1 yet"],
+            []
+        );
+    };
+    ($day:ident, $readme:expr, pending: p2) => {
+        $crate::example_tests!(
+            @tests $day,
+            $readme,
+            [],
+            [ignore = "no example answer for part 2 yet"]
+        );
+    };
+    (@tests $day:ident, $readme:expr, [$($p1:meta)?], [$($p2:meta)?]) => {
+        #[cfg(test)]
+        mod test {
+            #[test]
+            $(#[$p1])?
+            fn test_p1() -> anyhow::Result<()> {
+                $crate::examples::check::<super::$day>($readme, $crate::solution::Part::One)
+            }
+
+            #[test]
+            $(#[$p2])?
+            fn test_p2() -> anyhow::Result<()> {
+                $crate::examples::check::<super::$day>($readme, $crate::solution::Part::Two)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Display;
+
+    use super::{check, parse, Example};
+    use crate::solution::{Part, Solution};
+
+    /// Part 1 sums the input's numbers; part 2 isn't solved yet.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+        type Input = Vec<u32>;
+
+        fn parse(s: &str) -> anyhow::Result<Self::Input> {
+            Ok(s.split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<impl Display> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(_: &Self::Input) -> anyhow::Result<impl Display> {
+            Err::<u32, _>(anyhow::anyhow!("part 2 is not solved yet"))
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let readme = "```example part1=6\n1 2 3\n```\n";
+        assert!(check::<Sum>(readme, Part::One).is_ok());
+        // Nothing to check is an error rather than a pass.
+        let err = check::<Sum>(readme, Part::Two).unwrap_err();
+        assert_eq!(err.to_string(), "no examples with an answer for part 2");
+        assert!(check::<Sum>("```example\n```\n", Part::One).is_err());
+
+        let err = check::<Sum>("\n```example part1=7\n1 2 3\n```\n", Part::One).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "example on line 2, part 1: expected 7, got 6"
+        );
+    }
+
+    /// Part 1 has an answer and runs; part 2's test is generated ignored.
+    mod pending {
+        use super::Sum;
+
+        crate::example_tests!(Sum, "```example part1=6\n1 2 3\n```\n", pending: p2);
+    }
+
+    #[test]
+    fn test_parse() -> anyhow::Result<()> {
+        let readme = "\
+Some text.
+
+```text
+```example part1=1
+```
+
+```example part1=6 part2=max=3
+1,2,3
+```
+
+```example part2=5
+5
+```
+";
+        let examples = parse(readme)?;
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    line: 7,
+                    input: "1,2,3\n".into(),
+                    part1: Some("6".into()),
+                    part2: Some("max=3".into()),
+                },
+                Example {
+                    line: 11,
+                    input: "5\n".into(),
+                    part1: None,
+                    part2: Some("5".into()),
+                },
+            ]
+        );
+
+        assert!(parse("```example part3=1\n```\n").is_err());
+        assert!(parse("```example part1=1\n1\n").is_err());
+        Ok(())
+    }
+}
@@ -1,4 +1,21 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
+
+use crate::parse::{self, Line, ParseError};
+
+mod bitgrid;
+mod direction;
+mod lines;
+mod region;
+mod render;
+mod sparse;
+mod view;
+
+pub use bitgrid::{BitGrid, DirectionalBitGrid};
+pub use direction::{CardinalDirection, Direction8, HexDirection};
+pub use region::{Region, RegionId, Regions};
+pub use render::{Render, ToChar};
+pub use sparse::SparseGrid;
+pub use view::GridView;
 
 #[derive(Clone)]
 pub struct Grid<Cell> {
@@ -56,52 +73,130 @@ impl<T> Grid<T> {
         x >= 0 && (x as usize) < self.w && y >= 0 && (y as usize) < self.h
     }
 
-    pub fn at(&self, c @ Coord(x, y): Coord) -> Option<&T> {
+    pub fn at(&self, c: Coord) -> Option<&T> {
+        let idx = self.index_of(c)?;
+        Some(&self.cells[idx])
+    }
+
+    pub fn at_mut(&mut self, c: Coord) -> Option<&mut T> {
+        let idx = self.index_of(c)?;
+        Some(&mut self.cells[idx])
+    }
+
+    /// Position of `c` in the row-major cell storage, if it's in bounds.
+    pub fn index_of(&self, c @ Coord(x, y): Coord) -> Option<usize> {
         if !self.in_bounds(c) {
             return None;
         }
-        Some(&self.cells[x as usize + y as usize * self.w])
+        Some(x as usize + y as usize * self.w)
     }
 
-    pub fn at_mut(&mut self, c @ Coord(x, y): Coord) -> Option<&mut T> {
-        if !self.in_bounds(c) {
-            return None;
+    /// Inverse of `index_of`.
+    pub fn coord_of(&self, idx: usize) -> Coord {
+        Coord((idx % self.w) as i32, (idx / self.w) as i32)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse a grid in which some characters mark special positions, such as
+    /// a start or end point, rather than (or as well as) a kind of cell.
+    /// `marker` maps each marker character to its marker and the cell that
+    /// lies underneath it; every other character is parsed as a cell.
+    ///
+    /// Returns the grid along with the position of every marker, in reading
+    /// order.
+    pub fn parse_with_markers<M>(
+        s: &str,
+        mut marker: impl FnMut(char) -> Option<(M, T)>,
+    ) -> Result<(Self, Vec<(Coord, M)>), ParseError>
+    where
+        T: TryFrom<char>,
+        <T as TryFrom<char>>::Error: fmt::Display,
+    {
+        let mut markers = Vec::new();
+        let grid = Self::parse_by(s, |coord, c| match marker(c) {
+            Some((m, cell)) => {
+                markers.push((coord, m));
+                Ok(cell)
+            }
+            None => T::try_from(c).map_err(|e| e.to_string()),
+        })?;
+        Ok((grid, markers))
+    }
+
+    /// Parse a grid with one character per cell. Line endings may be LF or
+    /// CRLF, and trailing blank lines are ignored.
+    fn parse_by(
+        s: &str,
+        mut cell: impl FnMut(Coord, char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut lines: Vec<Line> = parse::lines(s)
+            .map(|l| Line {
+                text: l.text.trim_end_matches('\r'),
+                ..l
+            })
+            .collect();
+        while lines.last().is_some_and(|l| l.text.trim().is_empty()) {
+            lines.pop();
         }
-        Some(&mut self.cells[x as usize + y as usize * self.w])
+        let Some(first) = lines.first() else {
+            return Err(ParseError::at(s, 0..0, "no lines in input"));
+        };
+
+        let w = first.text.chars().count();
+        let mut cells = Vec::with_capacity(w * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            for (x, (offset, c)) in line.text.char_indices().enumerate() {
+                if x == w {
+                    return Err(line.error_span(
+                        offset..line.text.len(),
+                        format!("line is longer than line 1, which has {w} cells"),
+                    ));
+                }
+                let coord = Coord(x as i32, y as i32);
+                cells.push(cell(coord, c).map_err(|e| line.error_at(offset, e))?);
+            }
+            if cells.len() < (y + 1) * w {
+                return Err(line.error_at(
+                    line.text.len(),
+                    format!("line is shorter than line 1, which has {w} cells"),
+                ));
+            }
+        }
+
+        Ok(Self {
+            cells,
+            w,
+            h: lines.len(),
+        })
     }
 }
 
 impl<T> FromStr for Grid<T>
 where
     T: TryFrom<char>,
-    anyhow::Error: From<<T as TryFrom<char>>::Error>,
+    <T as TryFrom<char>>::Error: fmt::Display,
 {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lengths: Vec<usize> = s.lines().map(|l| l.len()).collect();
-        let Some(first) = lengths.first() else {
-            anyhow::bail!("no lines in input");
-        };
-        if lengths.iter().any(|len| len != first) {
-            anyhow::bail!("unequal line lengths");
-        }
-
-        let h = lengths.len();
-        let w = *first;
-        let cells = s
-            .chars()
-            .filter(|&c| c != '\n')
-            .map(T::try_from)
-            .collect::<Result<Vec<T>, _>>()?;
-
-        Self::new(cells, w, h)
+        Self::parse_by(s, |_, c| T::try_from(c).map_err(|e| e.to_string()))
     }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct Coord(pub i32, pub i32);
 
+impl Coord {
+    pub fn offset(self, delta: Delta) -> Self {
+        self + delta
+    }
+
+    pub fn manhattan_distance(self, other: Coord) -> u32 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+}
+
 impl std::ops::Add<Delta> for Coord {
     type Output = Coord;
 
@@ -118,6 +213,7 @@ impl std::ops::Sub<Delta> for Coord {
     }
 }
 
+/// `a - b` is the step that takes `a` to `b`: `a + (a - b) == b`.
 impl std::ops::Sub<Coord> for Coord {
     type Output = Delta;
 
@@ -126,6 +222,36 @@ impl std::ops::Sub<Coord> for Coord {
     }
 }
 
+/// Inclusive axis-aligned bounding box.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl Bounds {
+    pub fn point(c: Coord) -> Self {
+        Self { min: c, max: c }
+    }
+
+    pub fn extend(&mut self, Coord(x, y): Coord) {
+        self.min = Coord(self.min.0.min(x), self.min.1.min(y));
+        self.max = Coord(self.max.0.max(x), self.max.1.max(y));
+    }
+
+    pub fn contains(&self, Coord(x, y): Coord) -> bool {
+        (self.min.0..=self.max.0).contains(&x) && (self.min.1..=self.max.1).contains(&y)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1) as usize + 1
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Delta(pub i32, pub i32);
 impl std::ops::Mul<i32> for Delta {
@@ -143,6 +269,10 @@ impl std::ops::Neg for Delta {
 }
 
 impl Delta {
+    pub fn invert(self) -> Self {
+        -self
+    }
+
     pub fn directions() -> impl Iterator<Item = Self> {
         const ALL: [Delta; 8] = [
             Delta(-1, -1),
@@ -158,32 +288,48 @@ impl Delta {
     }
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum CardinalDirection {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+#[cfg(test)]
+mod test {
+    use super::{Coord, Grid};
 
-impl CardinalDirection {
-    pub fn rotate_clockwise(self) -> Self {
-        match self {
-            Self::Up => Self::Right,
-            Self::Right => Self::Down,
-            Self::Down => Self::Left,
-            Self::Left => Self::Up,
-        }
+    #[test]
+    fn test_parse_errors() -> anyhow::Result<()> {
+        let grid: Grid<char> = "ab\ncd".parse()?;
+        assert_eq!((grid.w, grid.h), (2, 2));
+
+        let err = "abc\nabcde\nab".parse::<Grid<char>>().err().unwrap();
+        assert_eq!((err.line, err.column, err.width), (2, 4, 2));
+
+        let err = "abc\nabc\nab".parse::<Grid<char>>().err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert!(err.message.contains("shorter"), "{err}");
+
+        let err = "".parse::<Grid<char>>().err().unwrap();
+        assert_eq!(err.message, "no lines in input");
+        Ok(())
     }
-}
 
-impl Into<Delta> for CardinalDirection {
-    fn into(self) -> Delta {
-        match self {
-            Self::Up => Delta(0, -1),
-            Self::Down => Delta(0, 1),
-            Self::Left => Delta(-1, 0),
-            Self::Right => Delta(1, 0),
+    #[test]
+    fn test_parse_line_endings() -> anyhow::Result<()> {
+        for input in ["ab\ncd", "ab\ncd\n", "ab\r\ncd\r\n", "ab\ncd\n\n  \n"] {
+            let grid: Grid<char> = input.parse()?;
+            assert_eq!((grid.w, grid.h), (2, 2), "{input:?}");
+            assert_eq!(
+                grid.iter_cells().map(|(_, &c)| c).collect::<String>(),
+                "abcd"
+            );
         }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_with_markers() -> anyhow::Result<()> {
+        let (grid, markers) = Grid::<char>::parse_with_markers("S.#\n.#E\n", |c| match c {
+            'S' | 'E' => Some((c, '.')),
+            _ => None,
+        })?;
+        assert_eq!(grid.to_string(), "..#\n.#.");
+        assert_eq!(markers, [(Coord(0, 0), 'S'), (Coord(2, 1), 'E')]);
+        Ok(())
     }
 }
@@ -0,0 +1,361 @@
+use super::{CardinalDirection, Coord, Grid};
+
+/// Fixed-size bitset shared by the bit grid types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bits {
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, i: usize) -> bool {
+        let (word, mask) = (i / 64, 1 << (i % 64));
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        !was_set
+    }
+
+    fn remove(&mut self, i: usize) -> bool {
+        let (word, mask) = (i / 64, 1 << (i % 64));
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    fn contains(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    fn zip_with(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, *b);
+        }
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        std::iter::zip(&self.words, &other.words).all(|(a, b)| a & !b == 0)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
+
+/// Grids of the same word count can still differ in shape, so set operations
+/// compare dimensions rather than leaving it to [`Bits`].
+fn assert_same_size(a: (usize, usize), b: (usize, usize)) {
+    assert_eq!(a, b, "bit grid size mismatch");
+}
+
+/// A set of coordinates within a `w`x`h` rectangle, stored as one bit per
+/// cell.
+///
+/// Set operations between grids of different sizes panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    bits: Bits,
+    pub w: usize,
+    pub h: usize,
+}
+
+impl BitGrid {
+    pub fn new(w: usize, h: usize) -> Self {
+        Self {
+            bits: Bits::new(w * h),
+            w,
+            h,
+        }
+    }
+
+    /// An empty set the same size as `grid`.
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.w, grid.h)
+    }
+
+    fn index_of(&self, Coord(x, y): Coord) -> Option<usize> {
+        let in_bounds = x >= 0 && (x as usize) < self.w && y >= 0 && (y as usize) < self.h;
+        in_bounds.then(|| x as usize + y as usize * self.w)
+    }
+
+    /// Add `c` to the set, returning whether it was newly added. Coordinates
+    /// outside the grid are never added.
+    pub fn insert(&mut self, c: Coord) -> bool {
+        self.index_of(c).is_some_and(|i| self.bits.insert(i))
+    }
+
+    pub fn remove(&mut self, c: Coord) -> bool {
+        self.index_of(c).is_some_and(|i| self.bits.remove(i))
+    }
+
+    pub fn contains(&self, c: Coord) -> bool {
+        self.index_of(c).is_some_and(|i| self.bits.contains(i))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Coord> + '_ {
+        let w = self.w;
+        self.bits
+            .iter()
+            .map(move |i| Coord((i % w) as i32, (i / w) as i32))
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.zip_with(&other.bits, |a, b| a | b);
+    }
+
+    pub fn intersect_with(&mut self, other: &Self) {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.zip_with(&other.bits, |a, b| a & b);
+    }
+
+    pub fn difference_with(&mut self, other: &Self) {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.zip_with(&other.bits, |a, b| a & !b);
+    }
+
+    pub fn symmetric_difference_with(&mut self, other: &Self) {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.zip_with(&other.bits, |a, b| a ^ b);
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.is_subset(&other.bits)
+    }
+}
+
+impl Extend<Coord> for BitGrid {
+    fn extend<I: IntoIterator<Item = Coord>>(&mut self, iter: I) {
+        for c in iter {
+            self.insert(c);
+        }
+    }
+}
+
+impl std::ops::BitOr<&BitGrid> for &BitGrid {
+    type Output = BitGrid;
+    fn bitor(self, rhs: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out.union_with(rhs);
+        out
+    }
+}
+
+impl std::ops::BitAnd<&BitGrid> for &BitGrid {
+    type Output = BitGrid;
+    fn bitand(self, rhs: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out.intersect_with(rhs);
+        out
+    }
+}
+
+impl std::ops::Sub<&BitGrid> for &BitGrid {
+    type Output = BitGrid;
+    fn sub(self, rhs: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out.difference_with(rhs);
+        out
+    }
+}
+
+impl std::ops::BitXor<&BitGrid> for &BitGrid {
+    type Output = BitGrid;
+    fn bitxor(self, rhs: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out.symmetric_difference_with(rhs);
+        out
+    }
+}
+
+/// A set of `(Coord, CardinalDirection)` pairs within a `w`x`h` rectangle,
+/// e.g. the states visited by something walking around a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionalBitGrid {
+    bits: Bits,
+    pub w: usize,
+    pub h: usize,
+}
+
+impl DirectionalBitGrid {
+    pub fn new(w: usize, h: usize) -> Self {
+        Self {
+            bits: Bits::new(w * h * 4),
+            w,
+            h,
+        }
+    }
+
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.w, grid.h)
+    }
+
+    fn index_of(&self, Coord(x, y): Coord, d: CardinalDirection) -> Option<usize> {
+        let in_bounds = x >= 0 && (x as usize) < self.w && y >= 0 && (y as usize) < self.h;
+        let dir = CardinalDirection::ALL.iter().position(|&a| a == d).unwrap();
+        in_bounds.then(|| (x as usize + y as usize * self.w) * 4 + dir)
+    }
+
+    pub fn insert(&mut self, c: Coord, d: CardinalDirection) -> bool {
+        self.index_of(c, d).is_some_and(|i| self.bits.insert(i))
+    }
+
+    pub fn remove(&mut self, c: Coord, d: CardinalDirection) -> bool {
+        self.index_of(c, d).is_some_and(|i| self.bits.remove(i))
+    }
+
+    pub fn contains(&self, c: Coord, d: CardinalDirection) -> bool {
+        self.index_of(c, d).is_some_and(|i| self.bits.contains(i))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, CardinalDirection)> + '_ {
+        let w = self.w;
+        self.bits.iter().map(move |i| {
+            let cell = i / 4;
+            let c = Coord((cell % w) as i32, (cell / w) as i32);
+            (c, CardinalDirection::ALL[i % 4])
+        })
+    }
+
+    /// The coordinates visited in any direction.
+    pub fn positions(&self) -> BitGrid {
+        let mut out = BitGrid::new(self.w, self.h);
+        out.extend(self.iter().map(|(c, _)| c));
+        out
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.zip_with(&other.bits, |a, b| a | b);
+    }
+
+    pub fn intersect_with(&mut self, other: &Self) {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.zip_with(&other.bits, |a, b| a & b);
+    }
+
+    pub fn difference_with(&mut self, other: &Self) {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.zip_with(&other.bits, |a, b| a & !b);
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        assert_same_size((self.w, self.h), (other.w, other.h));
+        self.bits.is_subset(&other.bits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BitGrid, DirectionalBitGrid};
+    use crate::grid::{CardinalDirection, Coord};
+
+    #[test]
+    fn test_bitgrid() {
+        let mut a = BitGrid::new(10, 13);
+        assert!(a.insert(Coord(0, 0)));
+        assert!(a.insert(Coord(9, 12)));
+        assert!(!a.insert(Coord(9, 12)));
+        assert!(!a.insert(Coord(10, 0)));
+        assert_eq!(a.len(), 2);
+
+        let mut b = BitGrid::new(10, 13);
+        b.insert(Coord(9, 12));
+        b.insert(Coord(3, 7));
+
+        assert_eq!((&a | &b).len(), 3);
+        assert_eq!((&a & &b).iter().collect::<Vec<_>>(), vec![Coord(9, 12)]);
+        assert_eq!((&a - &b).iter().collect::<Vec<_>>(), vec![Coord(0, 0)]);
+        assert_eq!((&a ^ &b).len(), 2);
+        assert!((&a & &b).is_subset(&b));
+        assert!(!a.is_subset(&b));
+
+        a.remove(Coord(0, 0));
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![Coord(9, 12)]);
+        a.clear();
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_directional() {
+        let mut visited = DirectionalBitGrid::new(4, 4);
+        assert!(visited.insert(Coord(1, 2), CardinalDirection::Up));
+        assert!(visited.insert(Coord(1, 2), CardinalDirection::Left));
+        assert!(!visited.insert(Coord(1, 2), CardinalDirection::Up));
+        assert!(visited.insert(Coord(3, 3), CardinalDirection::Down));
+
+        assert!(visited.contains(Coord(1, 2), CardinalDirection::Left));
+        assert!(!visited.contains(Coord(1, 2), CardinalDirection::Down));
+        assert_eq!(visited.len(), 3);
+        assert_eq!(visited.positions().len(), 2);
+        assert!(visited
+            .iter()
+            .any(|s| s == (Coord(3, 3), CardinalDirection::Down)));
+    }
+
+    #[test]
+    #[should_panic(expected = "bit grid size mismatch")]
+    fn test_subset_size_mismatch() {
+        BitGrid::new(10, 10).is_subset(&BitGrid::new(100, 100));
+    }
+
+    #[test]
+    #[should_panic(expected = "bit grid size mismatch")]
+    fn test_transposed_size_mismatch() {
+        // Both fit in a single word, so only the dimensions tell them apart.
+        BitGrid::new(2, 3).union_with(&BitGrid::new(3, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "bit grid size mismatch")]
+    fn test_directional_size_mismatch() {
+        DirectionalBitGrid::new(2, 3).is_subset(&DirectionalBitGrid::new(3, 2));
+    }
+}
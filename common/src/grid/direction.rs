@@ -0,0 +1,330 @@
+use super::Delta;
+use crate::hex::HexDelta;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum CardinalDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl CardinalDirection {
+    /// All four directions, clockwise from `Up`.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn rotate_clockwise(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn rotate_counter_clockwise(self) -> Self {
+        self.opposite().rotate_clockwise()
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// The direction of a unit step, if `delta` is one.
+    pub fn from_delta(delta: Delta) -> Option<Self> {
+        match delta {
+            Delta(0, -1) => Some(Self::Up),
+            Delta(0, 1) => Some(Self::Down),
+            Delta(-1, 0) => Some(Self::Left),
+            Delta(1, 0) => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+impl From<CardinalDirection> for Delta {
+    fn from(d: CardinalDirection) -> Delta {
+        match d {
+            CardinalDirection::Up => Delta(0, -1),
+            CardinalDirection::Down => Delta(0, 1),
+            CardinalDirection::Left => Delta(-1, 0),
+            CardinalDirection::Right => Delta(1, 0),
+        }
+    }
+}
+
+/// Accepts arrows (`^v<>`), compass points (`NSEW`) and `UDLR`.
+impl TryFrom<char> for CardinalDirection {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Self::Up),
+            'v' | 'S' | 'D' => Ok(Self::Down),
+            '<' | 'W' | 'L' => Ok(Self::Left),
+            '>' | 'E' | 'R' => Ok(Self::Right),
+            c => anyhow::bail!("invalid direction: {c}"),
+        }
+    }
+}
+
+/// The eight compass directions, including diagonals.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All eight directions, clockwise from `Up`.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    fn index(self) -> i32 {
+        Self::ALL.iter().position(|&d| d == self).unwrap() as i32
+    }
+
+    /// Turn clockwise by `steps` multiples of 45°. Negative values turn
+    /// counter-clockwise.
+    pub fn turn(self, steps: i32) -> Self {
+        Self::ALL[(self.index() + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn rotate_clockwise(self) -> Self {
+        self.turn(2)
+    }
+
+    pub fn rotate_counter_clockwise(self) -> Self {
+        self.turn(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    pub fn to_cardinal(self) -> Option<CardinalDirection> {
+        CardinalDirection::from_delta(self.into())
+    }
+
+    /// The direction of a unit step, if `delta` is one.
+    pub fn from_delta(delta: Delta) -> Option<Self> {
+        Self::ALL.into_iter().find(|&d| {
+            let Delta(x, y) = d.into();
+            (x, y) == (delta.0, delta.1)
+        })
+    }
+}
+
+impl From<Direction8> for Delta {
+    fn from(d: Direction8) -> Delta {
+        match d {
+            Direction8::Up => Delta(0, -1),
+            Direction8::UpRight => Delta(1, -1),
+            Direction8::Right => Delta(1, 0),
+            Direction8::DownRight => Delta(1, 1),
+            Direction8::Down => Delta(0, 1),
+            Direction8::DownLeft => Delta(-1, 1),
+            Direction8::Left => Delta(-1, 0),
+            Direction8::UpLeft => Delta(-1, -1),
+        }
+    }
+}
+
+impl From<CardinalDirection> for Direction8 {
+    fn from(d: CardinalDirection) -> Self {
+        match d {
+            CardinalDirection::Up => Self::Up,
+            CardinalDirection::Down => Self::Down,
+            CardinalDirection::Left => Self::Left,
+            CardinalDirection::Right => Self::Right,
+        }
+    }
+}
+
+/// Accepts the same characters as `CardinalDirection`.
+impl TryFrom<char> for Direction8 {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        CardinalDirection::try_from(c).map(Self::from)
+    }
+}
+
+/// The six directions between neighbouring hexes, named for the
+/// flat-topped layout described in [`crate::hex`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl HexDirection {
+    /// All six directions, clockwise from `North`.
+    pub const ALL: [Self; 6] = [
+        Self::North,
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    fn index(self) -> i32 {
+        Self::ALL.iter().position(|&d| d == self).unwrap() as i32
+    }
+
+    /// Turn clockwise by `steps` multiples of 60°. Negative values turn
+    /// counter-clockwise.
+    pub fn turn(self, steps: i32) -> Self {
+        Self::ALL[(self.index() + steps).rem_euclid(6) as usize]
+    }
+
+    pub fn rotate_clockwise(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn rotate_counter_clockwise(self) -> Self {
+        self.turn(-1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(3)
+    }
+
+    /// The unit step in this direction.
+    pub fn delta(self) -> HexDelta {
+        match self {
+            Self::North => HexDelta::NORTH,
+            Self::NorthEast => HexDelta::NORTH_EAST,
+            Self::SouthEast => HexDelta::SOUTH_EAST,
+            Self::South => HexDelta::SOUTH,
+            Self::SouthWest => HexDelta::SOUTH_WEST,
+            Self::NorthWest => HexDelta::NORTH_WEST,
+        }
+    }
+
+    /// The direction of a unit step, if `delta` is one.
+    pub fn from_delta(delta: HexDelta) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.delta() == delta)
+    }
+}
+
+impl From<HexDirection> for HexDelta {
+    fn from(d: HexDirection) -> HexDelta {
+        d.delta()
+    }
+}
+
+/// Accepts compass abbreviations in either case: `n`, `ne`, `se`, `s`, `sw`
+/// and `nw`.
+impl TryFrom<&str> for HexDirection {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.to_ascii_lowercase().as_str() {
+            "n" => Ok(Self::North),
+            "ne" => Ok(Self::NorthEast),
+            "se" => Ok(Self::SouthEast),
+            "s" => Ok(Self::South),
+            "sw" => Ok(Self::SouthWest),
+            "nw" => Ok(Self::NorthWest),
+            _ => anyhow::bail!("invalid hex direction: {s}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CardinalDirection, Direction8, HexDirection};
+    use crate::{grid::Delta, hex::HexDelta};
+
+    #[test]
+    fn test_rotation() {
+        for d in Direction8::ALL {
+            assert_eq!(d.turn(8), d);
+            assert_eq!(d.turn(-3), d.turn(5));
+            assert_eq!(d.rotate_clockwise().rotate_counter_clockwise(), d);
+            assert_eq!(d.opposite().opposite(), d);
+
+            let Delta(x, y) = d.into();
+            let Delta(ox, oy) = d.opposite().into();
+            assert_eq!((x, y), (-ox, -oy));
+            assert_eq!(Direction8::from_delta(d.into()), Some(d));
+        }
+
+        for d in CardinalDirection::ALL {
+            assert_eq!(d.rotate_counter_clockwise().rotate_clockwise(), d);
+            assert_eq!(
+                Direction8::from(d).rotate_clockwise(),
+                Direction8::from(d.rotate_clockwise())
+            );
+            assert_eq!(Direction8::from(d).to_cardinal(), Some(d));
+        }
+        assert_eq!(Direction8::Up.turn(-1), Direction8::UpLeft);
+        assert!(Direction8::DownLeft.is_diagonal());
+    }
+
+    #[test]
+    fn test_parse() -> anyhow::Result<()> {
+        let parsed = "^NU>ERvSD<WL"
+            .chars()
+            .map(CardinalDirection::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let expected = [
+            [CardinalDirection::Up; 3],
+            [CardinalDirection::Right; 3],
+            [CardinalDirection::Down; 3],
+            [CardinalDirection::Left; 3],
+        ]
+        .concat();
+        assert_eq!(parsed, expected);
+
+        assert!(CardinalDirection::try_from('x').is_err());
+        assert_eq!(Direction8::try_from('<')?, Direction8::Left);
+
+        assert_eq!(HexDirection::try_from("NE")?, HexDirection::NorthEast);
+        assert_eq!(HexDirection::try_from("sw")?, HexDirection::SouthWest);
+        assert!(HexDirection::try_from("e").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex_rotation() {
+        for d in HexDirection::ALL {
+            assert_eq!(d.turn(6), d);
+            assert_eq!(d.rotate_clockwise().rotate_counter_clockwise(), d);
+            assert_eq!(d.opposite().opposite(), d);
+            assert_eq!(d.opposite().delta(), -d.delta());
+            assert_eq!(HexDirection::from_delta(d.into()), Some(d));
+        }
+        assert_eq!(HexDirection::North.turn(-1), HexDirection::NorthWest);
+        assert_eq!(HexDirection::from_delta(HexDelta(1, 1)), None);
+    }
+}
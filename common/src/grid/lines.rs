@@ -0,0 +1,99 @@
+use super::{Coord, Delta, Grid};
+
+impl<T> Grid<T> {
+    /// Cells from `start` (inclusive) stepping by `delta` until leaving the
+    /// grid. A zero `delta` yields `start` once.
+    pub fn ray(&self, start: Coord, delta: Delta) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        let limit = if delta.0 == 0 && delta.1 == 0 {
+            1
+        } else {
+            usize::MAX
+        };
+        std::iter::successors(Some(start), move |&c| Some(c + delta))
+            .map_while(|c| self.at(c).map(|v| (c, v)))
+            .take(limit)
+    }
+
+    /// The first cell after `start` along `delta` for which `pred` holds,
+    /// e.g. the first obstacle in line of sight.
+    pub fn first_hit(
+        &self,
+        start: Coord,
+        delta: Delta,
+        mut pred: impl FnMut(Coord, &T) -> bool,
+    ) -> Option<(Coord, &T)> {
+        self.ray(start, delta).skip(1).find(|&(c, v)| pred(c, v))
+    }
+
+    /// Each row, top to bottom, as a left-to-right ray.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (Coord, &T)>> {
+        (0..self.h as i32).map(|y| self.ray(Coord(0, y), Delta(1, 0)))
+    }
+
+    /// Each column, left to right, as a top-to-bottom ray.
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (Coord, &T)>> {
+        (0..self.w as i32).map(|x| self.ray(Coord(x, 0), Delta(0, 1)))
+    }
+
+    /// Each `\` diagonal, running down and to the right, starting from the
+    /// bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Coord, &T)>> {
+        let (w, h) = (self.w as i32, self.h as i32);
+        let left = (0..h).rev().map(|y| Coord(0, y));
+        let top = (1..w).map(|x| Coord(x, 0));
+        left.chain(top).map(|c| self.ray(c, Delta(1, 1)))
+    }
+
+    /// Each `/` diagonal, running down and to the left, starting from the
+    /// top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Coord, &T)>> {
+        let (w, h) = (self.w as i32, self.h as i32);
+        let top = (0..w).map(|x| Coord(x, 0));
+        let right = (1..h).map(move |y| Coord(w - 1, y));
+        top.chain(right).map(|c| self.ray(c, Delta(-1, 1)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::{Coord, Delta, Grid};
+
+    const EXAMPLE: &str = "abc\ndef";
+
+    fn collect<'a>(it: impl Iterator<Item = (Coord, &'a char)>) -> String {
+        it.map(|(_, c)| *c).collect()
+    }
+
+    #[test]
+    fn test_lines() -> anyhow::Result<()> {
+        let grid: Grid<char> = EXAMPLE.parse()?;
+
+        let rows: Vec<String> = grid.rows().map(collect).collect();
+        assert_eq!(rows, ["abc", "def"]);
+
+        let cols: Vec<String> = grid.cols().map(collect).collect();
+        assert_eq!(cols, ["ad", "be", "cf"]);
+
+        let diags: Vec<String> = grid.diagonals().map(collect).collect();
+        assert_eq!(diags, ["d", "ae", "bf", "c"]);
+
+        let anti: Vec<String> = grid.anti_diagonals().map(collect).collect();
+        assert_eq!(anti, ["a", "bd", "ce", "f"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ray() -> anyhow::Result<()> {
+        let grid: Grid<char> = "a.#.\n....\n#...".parse()?;
+
+        assert_eq!(collect(grid.ray(Coord(3, 0), Delta(-1, 0))), ".#.a");
+        assert_eq!(collect(grid.ray(Coord(5, 0), Delta(-1, 0))), "");
+        assert_eq!(collect(grid.ray(Coord(1, 1), Delta(0, 0))), ".");
+
+        let hit = grid.first_hit(Coord(0, 0), Delta(1, 0), |_, &c| c == '#');
+        assert_eq!(hit, Some((Coord(2, 0), &'#')));
+        let hit = grid.first_hit(Coord(2, 0), Delta(1, 1), |_, &c| c == '#');
+        assert_eq!(hit, None);
+        Ok(())
+    }
+}
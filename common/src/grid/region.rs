@@ -0,0 +1,224 @@
+use std::collections::VecDeque;
+
+use super::{Bounds, Coord, Delta, Grid};
+
+/// Label assigned to each connected region, in the order regions are first
+/// encountered during a row-major scan.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RegionId(pub usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: RegionId,
+    /// Any cell belonging to the region.
+    pub representative: Coord,
+    pub area: usize,
+    /// Number of unit edges between the region and anything outside it.
+    pub perimeter: usize,
+    /// Number of straight fence segments, i.e. perimeter edges that form
+    /// a single line are counted once.
+    pub sides: usize,
+    pub bounds: Bounds,
+}
+
+pub struct Regions {
+    pub labels: Grid<RegionId>,
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn get(&self, id: RegionId) -> &Region {
+        &self.regions[id.0]
+    }
+
+    pub fn region_at(&self, c: Coord) -> Option<&Region> {
+        self.labels.at(c).map(|&id| self.get(id))
+    }
+
+    /// All cells belonging to region `id`.
+    pub fn cells(&self, id: RegionId) -> impl Iterator<Item = Coord> + '_ {
+        self.labels
+            .iter_cells()
+            .filter(move |(_, &label)| label == id)
+            .map(|(c, _)| c)
+    }
+}
+
+const CARDINALS: [Delta; 4] = [Delta(0, -1), Delta(1, 0), Delta(0, 1), Delta(-1, 0)];
+
+impl<T> Grid<T> {
+    /// Cells reachable from `start` by cardinal steps between cells for which
+    /// `same` holds.
+    pub fn flood_fill(&self, start: Coord, same: impl Fn(&T, &T) -> bool) -> Vec<Coord> {
+        let Some(idx) = self.index_of(start) else {
+            return Vec::new();
+        };
+        let mut seen = vec![false; self.cells.len()];
+        seen[idx] = true;
+        self.flood(start, &mut seen, &same)
+    }
+
+    /// Label connected groups of equal cells.
+    pub fn regions(&self) -> Regions
+    where
+        T: PartialEq,
+    {
+        self.regions_by(|a, b| a == b)
+    }
+
+    /// Label connected groups of cells, where two adjacent cells belong to the
+    /// same group if `same` holds for them.
+    pub fn regions_by(&self, same: impl Fn(&T, &T) -> bool) -> Regions {
+        let mut seen = vec![false; self.cells.len()];
+        let mut labels = vec![RegionId(0); self.cells.len()];
+        let mut members = Vec::new();
+
+        for idx in 0..self.cells.len() {
+            if seen[idx] {
+                continue;
+            }
+            seen[idx] = true;
+
+            let id = RegionId(members.len());
+            let cells = self.flood(self.coord_of(idx), &mut seen, &same);
+            for c in &cells {
+                labels[self.index_of(*c).unwrap()] = id;
+            }
+            members.push(cells);
+        }
+
+        let labels = Grid {
+            cells: labels,
+            w: self.w,
+            h: self.h,
+        };
+        let regions = members
+            .into_iter()
+            .enumerate()
+            .map(|(i, cells)| measure(&labels, RegionId(i), &cells))
+            .collect();
+
+        Regions { labels, regions }
+    }
+
+    // Breadth-first fill from `start`, which must already be marked in `seen`.
+    fn flood(&self, start: Coord, seen: &mut [bool], same: impl Fn(&T, &T) -> bool) -> Vec<Coord> {
+        let mut out = vec![start];
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(cur) = queue.pop_front() {
+            let here = self.at(cur).unwrap();
+            for delta in CARDINALS {
+                let next = cur + delta;
+                let Some(idx) = self.index_of(next) else {
+                    continue;
+                };
+                if seen[idx] || !same(here, &self.cells[idx]) {
+                    continue;
+                }
+                seen[idx] = true;
+                out.push(next);
+                queue.push_back(next);
+            }
+        }
+
+        out
+    }
+}
+
+fn measure(labels: &Grid<RegionId>, id: RegionId, cells: &[Coord]) -> Region {
+    let inside = |c: Coord| labels.at(c) == Some(&id);
+
+    let mut bounds = Bounds::point(cells[0]);
+    let mut perimeter = 0;
+    let mut corners = 0;
+
+    for &c in cells {
+        bounds.extend(c);
+        perimeter += CARDINALS.iter().filter(|&&d| !inside(c + d)).count();
+
+        // Each side of a polygon ends in exactly one corner, so counting
+        // corners counts sides.
+        for i in 0..4 {
+            let a = CARDINALS[i];
+            let b = CARDINALS[(i + 1) % 4];
+            let diag = Delta(a.0 + b.0, a.1 + b.1);
+            let (in_a, in_b) = (inside(c + a), inside(c + b));
+            let convex = !in_a && !in_b;
+            let concave = in_a && in_b && !inside(c + diag);
+            if convex || concave {
+                corners += 1;
+            }
+        }
+    }
+
+    Region {
+        id,
+        representative: cells[0],
+        area: cells.len(),
+        perimeter,
+        sides: corners,
+        bounds,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::{Bounds, Coord, Grid};
+
+    const EXAMPLE: &str = r#"AAAA
+BBCD
+BBCC
+EEEC"#;
+
+    #[test]
+    fn test_regions() -> anyhow::Result<()> {
+        let grid: Grid<char> = EXAMPLE.parse()?;
+        let regions = grid.regions();
+        assert_eq!(regions.regions.len(), 5);
+
+        let stats: Vec<(usize, usize, usize)> = regions
+            .regions
+            .iter()
+            .map(|r| (r.area, r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            stats,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+
+        let c = regions.region_at(Coord(2, 1)).unwrap();
+        assert_eq!(
+            c.bounds,
+            Bounds {
+                min: Coord(2, 1),
+                max: Coord(3, 3)
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_regions_by() -> anyhow::Result<()> {
+        let grid: Grid<char> = r#"OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO"#
+            .parse()?;
+
+        let regions = grid.regions();
+        let outer = regions.region_at(Coord(0, 0)).unwrap();
+        assert_eq!((outer.area, outer.perimeter, outer.sides), (21, 36, 20));
+
+        // Grouping every cell together gives a single square.
+        let all = grid.regions_by(|_, _| true);
+        assert_eq!(all.regions.len(), 1);
+        assert_eq!(all.regions[0].sides, 4);
+        assert_eq!(
+            grid.flood_fill(Coord(1, 1), |a, b| a == b),
+            vec![Coord(1, 1)]
+        );
+        Ok(())
+    }
+}
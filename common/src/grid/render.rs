@@ -0,0 +1,169 @@
+use std::fmt;
+
+use super::{CardinalDirection, Coord, Delta, Grid};
+
+/// Inverse of the `TryFrom<char>` conversion used to parse grids.
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl ToChar for CardinalDirection {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Down => 'v',
+            Self::Left => '<',
+            Self::Right => '>',
+        }
+    }
+}
+
+/// Renders one line per row, without a trailing newline, so that parsing the
+/// output gives back an identical grid.
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(|_, cell| cell.to_char()).fmt(f)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draw the grid to a character canvas, which overlays can then be added
+    /// to before printing.
+    pub fn render(&self, f: impl Fn(Coord, &T) -> char) -> Render {
+        let cells = self.iter_cells().map(|(c, v)| f(c, v)).collect();
+        Render(Grid {
+            cells,
+            w: self.w,
+            h: self.h,
+        })
+    }
+}
+
+/// A rendered grid. Overlay methods draw on top of what's already there and
+/// ignore coordinates outside the grid.
+#[derive(Clone)]
+pub struct Render(Grid<char>);
+
+impl Render {
+    pub fn overlay(mut self, coords: impl IntoIterator<Item = Coord>, ch: char) -> Self {
+        for c in coords {
+            self.set(c, ch);
+        }
+        self
+    }
+
+    /// Draw a direction arrow at `pos`, e.g. for a guard's heading.
+    pub fn arrow(mut self, pos: Coord, direction: CardinalDirection) -> Self {
+        self.set(pos, direction.to_char());
+        self
+    }
+
+    /// Draw a path as a sequence of arrows, each pointing to the next cell.
+    /// Steps that aren't a single cardinal move, and the final cell, are
+    /// drawn as `o`.
+    pub fn path(mut self, path: &[Coord]) -> Self {
+        for pair in path.windows(2) {
+            let step = Delta(pair[1].0 - pair[0].0, pair[1].1 - pair[0].1);
+            let ch = CardinalDirection::from_delta(step).map_or('o', |d| d.to_char());
+            self.set(pair[0], ch);
+        }
+        if let Some(&last) = path.last() {
+            self.set(last, 'o');
+        }
+        self
+    }
+
+    fn set(&mut self, c: Coord, ch: char) {
+        if let Some(cell) = self.0.at_mut(c) {
+            *cell = ch;
+        }
+    }
+}
+
+impl fmt::Display for Render {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.0.cells.chunks(self.0.w.max(1)).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for ch in row {
+                write!(f, "{ch}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToChar;
+    use crate::grid::{CardinalDirection, Coord, Grid};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = anyhow::Error;
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Self::Empty),
+                '#' => Ok(Self::Wall),
+                c => anyhow::bail!("invalid cell {c}"),
+            }
+        }
+    }
+
+    impl ToChar for Cell {
+        fn to_char(&self) -> char {
+            match self {
+                Self::Empty => '.',
+                Self::Wall => '#',
+            }
+        }
+    }
+
+    const EXAMPLE: &str = r#"..#.
+....
+#...
+...#"#;
+
+    #[test]
+    fn test_round_trip() -> anyhow::Result<()> {
+        let grid: Grid<Cell> = EXAMPLE.parse()?;
+        assert_eq!(grid.to_string(), EXAMPLE);
+
+        let reparsed: Grid<Cell> = grid.to_string().parse()?;
+        assert!(reparsed.iter_cells().all(|(c, v)| grid.at(c) == Some(v)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlays() -> anyhow::Result<()> {
+        let grid: Grid<Cell> = EXAMPLE.parse()?;
+        let path = [Coord(0, 0), Coord(0, 1), Coord(1, 1), Coord(2, 1)];
+        let out = grid
+            .render(|_, c| c.to_char())
+            .overlay([Coord(3, 0), Coord(9, 9)], 'X')
+            .path(&path)
+            .arrow(Coord(1, 3), CardinalDirection::Up)
+            .to_string();
+
+        assert_eq!(
+            out,
+            r#"v.#X
+>>o.
+#...
+.^.#"#
+        );
+        Ok(())
+    }
+}
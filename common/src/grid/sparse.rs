@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use super::{Bounds, Coord, Grid};
+
+/// An unbounded grid that only stores occupied cells.
+///
+/// Coordinates may be negative. The bounding box of the occupied cells is
+/// maintained as cells are inserted and removed.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Convert the cells of `grid` for which `keep` holds.
+    pub fn from_grid_where(grid: Grid<T>, keep: impl Fn(&T) -> bool) -> Self {
+        let w = grid.w;
+        let mut out = Self::new();
+        for (i, cell) in grid.cells.into_iter().enumerate() {
+            if keep(&cell) {
+                out.insert(Coord((i % w) as i32, (i / w) as i32), cell);
+            }
+        }
+        out
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Bounding box of the occupied cells, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn contains(&self, c: Coord) -> bool {
+        self.cells.contains_key(&c)
+    }
+
+    pub fn at(&self, c: Coord) -> Option<&T> {
+        self.cells.get(&c)
+    }
+
+    pub fn at_mut(&mut self, c: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&c)
+    }
+
+    /// Get the value at `c`, inserting `f()` first if it's unoccupied.
+    pub fn at_or_insert_with(&mut self, c: Coord, f: impl FnOnce() -> T) -> &mut T {
+        self.extend_bounds(c);
+        self.cells.entry(c).or_insert_with(f)
+    }
+
+    pub fn insert(&mut self, c: Coord, value: T) -> Option<T> {
+        self.extend_bounds(c);
+        self.cells.insert(c, value)
+    }
+
+    pub fn remove(&mut self, c: Coord) -> Option<T> {
+        let removed = self.cells.remove(&c)?;
+
+        // Only cells on the edge of the bounding box can shrink it.
+        if let Some(b) = self.bounds {
+            if c.0 == b.min.0 || c.0 == b.max.0 || c.1 == b.min.1 || c.1 == b.max.1 {
+                self.recompute_bounds();
+            }
+        }
+        Some(removed)
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    /// Iterate over occupied cells in arbitrary order.
+    pub fn iter_cells(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(&c, v)| (c, v))
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> SparseGrid<U> {
+        SparseGrid {
+            cells: self.cells.into_iter().map(|(c, v)| (c, f(v))).collect(),
+            bounds: self.bounds,
+        }
+    }
+
+    /// Copy the bounding box into a dense grid, filling unoccupied cells with
+    /// `fill`. Also returns the coordinate that the dense grid's `Coord(0, 0)`
+    /// corresponds to.
+    pub fn to_grid(&self, fill: T) -> Option<(Grid<T>, Coord)>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds?;
+        let (w, h) = (bounds.width(), bounds.height());
+        let mut cells = vec![fill; w * h];
+        for (&Coord(x, y), v) in &self.cells {
+            let (dx, dy) = ((x - bounds.min.0) as usize, (y - bounds.min.1) as usize);
+            cells[dx + dy * w] = v.clone();
+        }
+        Some((Grid { cells, w, h }, bounds.min))
+    }
+
+    fn extend_bounds(&mut self, c: Coord) {
+        match &mut self.bounds {
+            Some(b) => b.extend(c),
+            None => self.bounds = Some(Bounds::point(c)),
+        }
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = None;
+        let coords: Vec<Coord> = self.cells.keys().copied().collect();
+        for c in coords {
+            self.extend_bounds(c);
+        }
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        Self::from_grid_where(grid, |_| true)
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut out = Self::new();
+        for (c, v) in iter {
+            out.insert(c, v);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SparseGrid;
+    use crate::grid::{Bounds, Coord, Grid};
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Coord(0, 0), 'a');
+        grid.insert(Coord(-3, 2), 'b');
+        grid.insert(Coord(4, -1), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Coord(-3, -1),
+                max: Coord(4, 2)
+            })
+        );
+
+        grid.remove(Coord(-3, 2));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Coord(0, -1),
+                max: Coord(4, 0)
+            })
+        );
+
+        *grid.at_or_insert_with(Coord(0, 0), || 'z') = 'd';
+        assert_eq!(grid.at(Coord(0, 0)), Some(&'d'));
+        assert_eq!(grid.len(), 2);
+    }
+
+    #[test]
+    fn test_dense_round_trip() -> anyhow::Result<()> {
+        let dense: Grid<char> = "#..\n.#.\n..#".parse()?;
+        let sparse = SparseGrid::from_grid_where(dense, |&c| c == '#');
+        assert_eq!(sparse.len(), 3);
+
+        let shifted: SparseGrid<char> = sparse
+            .iter_cells()
+            .map(|(Coord(x, y), &v)| (Coord(x - 5, y + 1), v))
+            .collect();
+        let (back, origin) = shifted.to_grid('.').unwrap();
+        assert_eq!(origin, Coord(-5, 1));
+        assert_eq!(back.to_string(), "#..\n.#.\n..#");
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use super::{Coord, Delta, Grid};
+
+/// A borrowed, possibly rotated or flipped, rectangular window onto a `Grid`.
+///
+/// View coordinate `(x, y)` maps to `origin + x_axis * x + y_axis * y` in the
+/// underlying grid, so transforming a view never copies any cells.
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coord,
+    x_axis: Delta,
+    y_axis: Delta,
+    pub w: usize,
+    pub h: usize,
+}
+
+// Not derived, since that would needlessly require `T: Copy`.
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Grid<T> {
+    /// A view of the whole grid.
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            origin: Coord(0, 0),
+            x_axis: Delta(1, 0),
+            y_axis: Delta(0, 1),
+            w: self.w,
+            h: self.h,
+        }
+    }
+
+    /// A view of the `w`x`h` rectangle whose top-left corner is `min`, or
+    /// `None` if it doesn't fit in the grid.
+    pub fn sub_view(&self, min: Coord, w: usize, h: usize) -> Option<GridView<'_, T>> {
+        self.view().sub_view(min, w, h)
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().transpose().to_grid()
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().rotate_clockwise().to_grid()
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().rotate_counter_clockwise().to_grid()
+    }
+
+    /// Mirror left-to-right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    /// Mirror top-to-bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().flip_vertical().to_grid()
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn in_bounds(&self, Coord(x, y): Coord) -> bool {
+        x >= 0 && (x as usize) < self.w && y >= 0 && (y as usize) < self.h
+    }
+
+    /// The coordinate in the underlying grid that `c` refers to.
+    pub fn to_grid_coord(&self, Coord(x, y): Coord) -> Coord {
+        self.origin + self.x_axis * x + self.y_axis * y
+    }
+
+    pub fn at(&self, c: Coord) -> Option<&'a T> {
+        if !self.in_bounds(c) {
+            return None;
+        }
+        self.grid.at(self.to_grid_coord(c))
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = Coord> {
+        let w = self.w as i32;
+        (0..self.h as i32).flat_map(move |y| (0..w).map(move |x| Coord(x, y)))
+    }
+
+    pub fn iter_cells(&self) -> impl Iterator<Item = (Coord, &'a T)> + '_ {
+        self.iter_coords().map(|c| (c, self.at(c).unwrap()))
+    }
+
+    pub fn sub_view(&self, min: Coord, w: usize, h: usize) -> Option<Self> {
+        let max = Coord(min.0 + w as i32 - 1, min.1 + h as i32 - 1);
+        if w == 0 || h == 0 || !self.in_bounds(min) || !self.in_bounds(max) {
+            return None;
+        }
+        Some(Self {
+            origin: self.to_grid_coord(min),
+            w,
+            h,
+            ..*self
+        })
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            x_axis: self.y_axis,
+            y_axis: self.x_axis,
+            w: self.h,
+            h: self.w,
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        Self {
+            origin: self.to_grid_coord(Coord(self.w as i32 - 1, 0)),
+            x_axis: -self.x_axis,
+            ..self
+        }
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        Self {
+            origin: self.to_grid_coord(Coord(0, self.h as i32 - 1)),
+            y_axis: -self.y_axis,
+            ..self
+        }
+    }
+
+    pub fn rotate_clockwise(self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_counter_clockwise(self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    /// Copy the view into a new grid.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.iter_cells().map(|(_, v)| v.clone()).collect(),
+            w: self.w,
+            h: self.h,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::{Coord, Grid};
+
+    const EXAMPLE: &str = "abc\ndef";
+
+    #[test]
+    fn test_transforms() -> anyhow::Result<()> {
+        let grid: Grid<char> = EXAMPLE.parse()?;
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+
+        let spun = (0..4).fold(grid.clone(), |g, _| g.rotate_clockwise());
+        assert_eq!(spun.to_string(), EXAMPLE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sub_view() -> anyhow::Result<()> {
+        let grid: Grid<char> = "abcd\nefgh\nijkl".parse()?;
+
+        let view = grid.sub_view(Coord(1, 1), 2, 2).unwrap();
+        assert_eq!(view.at(Coord(0, 0)), Some(&'f'));
+        assert_eq!(view.at(Coord(2, 0)), None);
+        assert_eq!(view.to_grid().to_string(), "fg\njk");
+
+        let rotated = view.rotate_clockwise();
+        assert_eq!(rotated.to_grid().to_string(), "jf\nkg");
+        assert_eq!(rotated.to_grid_coord(Coord(0, 0)), Coord(1, 2));
+
+        assert!(grid.sub_view(Coord(3, 0), 2, 1).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_without_copy_cells() -> anyhow::Result<()> {
+        let grid: Grid<String> = EXAMPLE.parse()?;
+        let view = grid.view();
+        let copy = view;
+        assert_eq!(view.at(Coord(2, 1)), copy.at(Coord(2, 1)));
+        Ok(())
+    }
+}
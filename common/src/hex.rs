@@ -0,0 +1,161 @@
+//! Hexagonal grids in axial coordinates.
+//!
+//! A hex is identified by `(q, r)`; the third cube coordinate is implied by
+//! `q + r + s == 0`. Directions are named for a flat-topped layout, where `q`
+//! increases to the south-east and `r` increases to the south.
+
+use crate::grid::HexDirection;
+
+/// A cell in a hexagonal grid, in axial coordinates.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Hex(pub i32, pub i32);
+
+impl Hex {
+    pub fn q(self) -> i32 {
+        self.0
+    }
+
+    pub fn r(self) -> i32 {
+        self.1
+    }
+
+    pub fn s(self) -> i32 {
+        -self.0 - self.1
+    }
+
+    /// The `(q, r, s)` cube coordinates of this hex.
+    pub fn cube(self) -> (i32, i32, i32) {
+        (self.q(), self.r(), self.s())
+    }
+
+    /// Build a hex from cube coordinates, which must sum to zero.
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Option<Self> {
+        (q + r + s == 0).then_some(Self(q, r))
+    }
+
+    pub fn offset(self, delta: HexDelta) -> Self {
+        self + delta
+    }
+
+    /// Number of steps between two hexes.
+    pub fn distance(self, other: Hex) -> u32 {
+        let HexDelta(dq, dr) = self - other;
+        (dq.unsigned_abs() + dr.unsigned_abs() + (dq + dr).unsigned_abs()) / 2
+    }
+
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        HexDelta::directions().map(move |d| self + d)
+    }
+
+    /// Every hex exactly `radius` steps away, walking around the ring.
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Self> {
+        let radius = radius as i32;
+        let start = self + HexDelta::SOUTH_WEST * radius;
+        let sides = if radius == 0 { 0 } else { 6 };
+        let center = (radius == 0).then_some(self);
+
+        let walk = HexDelta::directions()
+            .take(sides)
+            .flat_map(move |d| std::iter::repeat_n(d, radius as usize));
+        let perimeter = walk.scan(start, |cur, d| {
+            let here = *cur;
+            *cur = *cur + d;
+            Some(here)
+        });
+        center.into_iter().chain(perimeter)
+    }
+}
+
+impl std::ops::Add<HexDelta> for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: HexDelta) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl std::ops::Sub<HexDelta> for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: HexDelta) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+/// `a - b` is the step that takes `a` to `b`, as for `Coord`.
+impl std::ops::Sub<Hex> for Hex {
+    type Output = HexDelta;
+
+    fn sub(self, rhs: Hex) -> Self::Output {
+        HexDelta(rhs.0 - self.0, rhs.1 - self.1)
+    }
+}
+
+/// A step between hexes, in axial coordinates.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct HexDelta(pub i32, pub i32);
+
+impl std::ops::Mul<i32> for HexDelta {
+    type Output = Self;
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl std::ops::Neg for HexDelta {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+impl HexDelta {
+    pub const NORTH: Self = Self(0, -1);
+    pub const NORTH_EAST: Self = Self(1, -1);
+    pub const SOUTH_EAST: Self = Self(1, 0);
+    pub const SOUTH: Self = Self(0, 1);
+    pub const SOUTH_WEST: Self = Self(-1, 1);
+    pub const NORTH_WEST: Self = Self(-1, 0);
+
+    pub fn invert(self) -> Self {
+        -self
+    }
+
+    /// The six unit steps, clockwise from north.
+    pub fn directions() -> impl Iterator<Item = Self> {
+        HexDirection::ALL.into_iter().map(HexDirection::delta)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{Hex, HexDelta};
+
+    #[test]
+    fn test_distance() {
+        let origin = Hex(0, 0);
+        assert!(origin.neighbors().all(|n| n.distance(origin) == 1));
+        assert_eq!(Hex(3, -1).distance(Hex(-2, 2)), 5);
+        assert_eq!(Hex::from_cube(1, 2, -3), Some(Hex(1, 2)));
+        assert_eq!(Hex::from_cube(1, 2, 3), None);
+
+        let walked = origin + HexDelta::NORTH_EAST * 2 + HexDelta::SOUTH;
+        assert_eq!(walked.cube(), (2, -1, -1));
+        assert_eq!(origin - walked, HexDelta(2, -1));
+    }
+
+    #[test]
+    fn test_ring() {
+        let center = Hex(2, -1);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+
+        for radius in 1..4 {
+            let ring: Vec<Hex> = center.ring(radius).collect();
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|h| h.distance(center) == radius));
+            assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+        }
+    }
+}
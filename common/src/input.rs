@@ -0,0 +1,164 @@
+use std::{
+    fmt,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+/// Directory searched for `dayNN.txt` when `--input-dir` isn't given.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Command-line flags controlling where puzzle input is read from.
+///
+/// In order of preference: `--input <path>` (or `--input -` for stdin), then
+/// `<input-dir>/dayNN.txt`, then the input compiled into the day's crate.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct InputArgs {
+    /// Read puzzle input from this file, or `-` for stdin.
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Directory containing `dayNN.txt` inputs [default: inputs].
+    #[arg(long, value_name = "DIR")]
+    pub input_dir: Option<PathBuf>,
+}
+
+/// Where a puzzle input was loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Embedded,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Embedded => write!(f, "<embedded>"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PuzzleInput {
+    pub source: Source,
+    pub text: String,
+}
+
+pub fn day_file_name(day: u32) -> String {
+    format!("day{day:02}.txt")
+}
+
+impl InputArgs {
+    /// Load the input for `day`, falling back to `embedded`.
+    pub fn load(&self, day: u32, embedded: &str) -> anyhow::Result<PuzzleInput> {
+        if let Some(path) = &self.input {
+            return if path == Path::new("-") {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .context("failed to read input from stdin")?;
+                Ok(PuzzleInput {
+                    source: Source::Stdin,
+                    text,
+                })
+            } else {
+                read_file(path)
+            };
+        }
+
+        let dir = self
+            .input_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+        let path = dir.join(day_file_name(day));
+        if path.exists() {
+            return read_file(&path);
+        }
+        if self.input_dir.is_some() {
+            anyhow::bail!(
+                "no input for day {day} in {}: expected {}",
+                dir.display(),
+                path.display()
+            );
+        }
+
+        if embedded.trim().is_empty() {
+            anyhow::bail!(
+                "no input for day {day}: pass --input <path>, pipe it in with --input -, \
+                 or save it to {}",
+                path.display()
+            );
+        }
+        Ok(PuzzleInput {
+            source: Source::Embedded,
+            text: embedded.to_string(),
+        })
+    }
+}
+
+fn read_file(path: &Path) -> anyhow::Result<PuzzleInput> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read input file {}", path.display()))?;
+    Ok(PuzzleInput {
+        source: Source::File(path.to_owned()),
+        text,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{InputArgs, Source};
+
+    #[test]
+    fn test_load() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join("day03.txt"), "from dir")?;
+
+        let args = InputArgs {
+            input: None,
+            input_dir: Some(dir.clone()),
+        };
+        let loaded = args.load(3, "embedded")?;
+        assert_eq!(loaded.text, "from dir");
+        assert_eq!(loaded.source, Source::File(dir.join("day03.txt")));
+
+        // An explicit directory without the day's file is an error rather
+        // than a silent fallback.
+        let err = args.load(4, "embedded").unwrap_err();
+        assert!(err.to_string().contains("day04.txt"), "{err}");
+
+        let args = InputArgs {
+            input: Some(dir.join("missing.txt")),
+            input_dir: None,
+        };
+        let err = args.load(3, "embedded").unwrap_err();
+        assert!(err.to_string().contains("missing.txt"), "{err}");
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_embedded_fallback() -> anyhow::Result<()> {
+        let args = InputArgs {
+            input: None,
+            input_dir: None,
+        };
+        // No inputs/ directory exists relative to the test's working dir.
+        assert!(!PathBuf::from("inputs").exists());
+
+        let loaded = args.load(1, "1 2\n")?;
+        assert_eq!(loaded.source, Source::Embedded);
+
+        let err = args.load(1, "").unwrap_err();
+        assert!(err.to_string().contains("--input"), "{err}");
+        Ok(())
+    }
+}
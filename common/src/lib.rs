@@ -1 +1,11 @@
+pub mod answers;
+pub mod bench;
+pub mod coord3;
+pub mod cycle;
+pub mod examples;
 pub mod grid;
+pub mod hex;
+pub mod input;
+pub mod parse;
+pub mod search;
+pub mod solution;
@@ -0,0 +1,340 @@
+//! Helpers for the line- and section-oriented formats most puzzle inputs use.
+//!
+//! Everything here keeps track of where in the input it is, so that errors
+//! name the line and column of the offending text rather than just saying
+//! that some integer somewhere was invalid.
+
+use std::{fmt, ops::Range, str::FromStr};
+
+/// A parse failure at a 1-based line and column of the input, spanning
+/// `width` characters of that line. Displays like a compiler diagnostic:
+///
+/// ```text
+/// line 2, column 8: invalid value "x5": invalid digit found in string
+///   |
+/// 2 | 83: 17 x5
+///   |        ^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+    pub message: String,
+    /// The full text of the offending line.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error covering the byte range `span` of `source`. Spans that run
+    /// past the end of their first line are cut short there.
+    pub fn at(source: &str, span: Range<usize>, message: impl Into<String>) -> Self {
+        let start = span.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let line_end = if source[start..line_end].ends_with('\r') {
+            line_end - 1
+        } else {
+            line_end
+        };
+        let mut end = span.end.clamp(start, line_end);
+        while !source.is_char_boundary(end) {
+            end += 1;
+        }
+
+        Self {
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            width: source[start..end].chars().count().max(1),
+            message: message.into(),
+            source_line: source[line_start..line_end].to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of input, along with its 1-based line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Every line of `s`, numbered from 1.
+pub fn lines(s: &str) -> impl Iterator<Item = Line<'_>> {
+    s.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+/// A run of consecutive non-blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> + '_ {
+        self.lines.iter().copied()
+    }
+
+    /// Line number of the section's first line.
+    pub fn first_line(&self) -> usize {
+        self.lines[0].number
+    }
+}
+
+/// Split `s` into sections separated by one or more blank lines. Leading and
+/// trailing blank lines are ignored.
+pub fn sections(s: &str) -> impl Iterator<Item = Section<'_>> {
+    let mut lines = lines(s).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|l| l.text.trim().is_empty()).is_some() {}
+        let section: Vec<Line> =
+            std::iter::from_fn(|| lines.next_if(|l| !l.text.trim().is_empty())).collect();
+        (!section.is_empty()).then_some(Section { lines: section })
+    })
+}
+
+impl<'a> Line<'a> {
+    /// An error pointing at the character starting at byte `offset`.
+    pub fn error_at(&self, offset: usize, message: impl Into<String>) -> ParseError {
+        let len = self.text[offset..].chars().next().map_or(0, char::len_utf8);
+        self.error_span(offset..offset + len, message)
+    }
+
+    /// An error pointing at `part`, which must be a slice of this line.
+    pub fn error_in(&self, part: &str, message: impl Into<String>) -> ParseError {
+        let offset = self.offset_of(part);
+        self.error_span(offset..offset + part.len(), message)
+    }
+
+    /// An error covering the byte range `span` of this line.
+    pub fn error_span(&self, span: Range<usize>, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            ..ParseError::at(self.text, span, message)
+        }
+    }
+
+    fn offset_of(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        assert!(
+            offset <= self.text.len(),
+            "{part:?} is not part of the line"
+        );
+        offset
+    }
+
+    fn parse_field<T>(&self, field: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        if field.is_empty() {
+            return Err(self.error_in(field, "expected a value"));
+        }
+        field
+            .parse()
+            .map_err(|e| self.error_in(field, format!("invalid value {field:?}: {e}")))
+    }
+
+    fn parse_list<T>(&self, text: &'a str, sep: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        if sep.trim().is_empty() {
+            text.split_whitespace()
+                .map(|f| self.parse_field(f))
+                .collect()
+        } else {
+            text.split(sep)
+                .map(|f| self.parse_field(f.trim()))
+                .collect()
+        }
+    }
+
+    /// Parse the whole line as a single value.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.parse_field(self.text.trim())
+    }
+
+    /// Parse a list of values separated by `sep`. Values are trimmed, and a
+    /// whitespace separator matches any run of whitespace.
+    pub fn list<T>(&self, sep: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.parse_list(self.text, sep)
+    }
+
+    /// Parse exactly two values separated by `sep`.
+    pub fn pair<T>(&self, sep: &str) -> Result<(T, T), ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let Some((a, b)) = self.text.split_once(sep) else {
+            return Err(self.error_at(self.text.len(), format!("expected {sep:?}")));
+        };
+        Ok((self.parse_field(a.trim())?, self.parse_field(b.trim())?))
+    }
+
+    /// Every integer in the line, ignoring whatever text surrounds them. A
+    /// `-` immediately before a digit makes the integer negative.
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut ints = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            ints.push(self.parse_field(&self.text[start..i])?);
+        }
+        Ok(ints)
+    }
+
+    /// Parse a `key: values` line, where the key and values are separated by
+    /// `key_sep` and the values by `value_sep` (as for [`Line::list`]).
+    pub fn key_values<K, V>(
+        &self,
+        key_sep: &str,
+        value_sep: &str,
+    ) -> Result<(K, Vec<V>), ParseError>
+    where
+        K: FromStr,
+        K::Err: fmt::Display,
+        V: FromStr,
+        V::Err: fmt::Display,
+    {
+        let Some((key, values)) = self.text.split_once(key_sep) else {
+            return Err(self.error_at(self.text.len(), format!("expected {key_sep:?}")));
+        };
+        Ok((
+            self.parse_field(key.trim())?,
+            self.parse_list(values, value_sep)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lines, sections, ParseError};
+
+    #[test]
+    fn test_sections() {
+        let input = "\n1|2\n3|4\n\n\n5,6\n\n";
+        let sections: Vec<_> = sections(input).collect();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].first_line(), 2);
+        assert_eq!(sections[1].first_line(), 6);
+        let texts: Vec<&str> = sections[0].lines().map(|l| l.text).collect();
+        assert_eq!(texts, ["1|2", "3|4"]);
+        assert_eq!(super::sections("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_lists() -> anyhow::Result<()> {
+        let line = lines("x\n7  6 4").nth(1).unwrap();
+        assert_eq!(line.list::<u32>(" ")?, [7, 6, 4]);
+        assert_eq!(lines("1, 2,3").next().unwrap().list::<u8>(",")?, [1, 2, 3]);
+        assert_eq!(lines("47|53").next().unwrap().pair::<u32>("|")?, (47, 53));
+
+        let (target, values) = lines("3267: 81 40 27")
+            .next()
+            .unwrap()
+            .key_values::<i64, i64>(": ", " ")?;
+        assert_eq!((target, values), (3267, vec![81, 40, 27]));
+
+        let ints = lines("p=0,-4 v=-3,12 x-y").next().unwrap().ints::<i32>()?;
+        assert_eq!(ints, [0, -4, -3, 12]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = lines("1 2\n3 x4 5")
+            .nth(1)
+            .unwrap()
+            .list::<u32>(" ")
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert!(err.message.contains("\"x4\""), "{err}");
+
+        let err = lines("1,2,,3")
+            .next()
+            .unwrap()
+            .list::<u32>(",")
+            .unwrap_err();
+        assert_eq!(err.column, 5);
+
+        let err = lines("47-53").next().unwrap().pair::<u32>("|").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 1,
+                column: 6,
+                width: 1,
+                message: "expected \"|\"".into(),
+                source_line: "47-53".into(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: expected \"|\"\n  |\n1 | 47-53\n  |      ^"
+        );
+
+        let err = lines("a 300").next().unwrap().ints::<u8>().unwrap_err();
+        assert_eq!((err.column, err.width), (3, 3));
+
+        let source = "ok\r\nnot ok\r\n";
+        let err = ParseError::at(source, 8..100, "bad");
+        assert_eq!((err.line, err.column, err.width), (2, 5, 2));
+        assert_eq!(err.source_line, "not ok");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: bad\n  |\n2 | not ok\n  |     ^^"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use crate::grid::{Coord, Delta, Grid};
+
+/// Which cells count as adjacent when expanding a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbors {
+    /// Up, down, left and right.
+    Cardinal,
+    /// Cardinal directions plus diagonals.
+    All,
+}
+
+impl Neighbors {
+    pub fn deltas(self) -> impl Iterator<Item = Delta> {
+        Delta::directions().filter(move |d| self == Self::All || d.0 == 0 || d.1 == 0)
+    }
+}
+
+/// Distances and predecessors produced by a search from a single start cell.
+#[derive(Clone)]
+pub struct SearchResult {
+    pub start: Coord,
+    dist: Grid<Option<u32>>,
+    prev: Grid<Option<Coord>>,
+}
+
+impl SearchResult {
+    fn new<T>(grid: &Grid<T>, start: Coord) -> Self {
+        let dist = Grid::new(vec![None; grid.w * grid.h], grid.w, grid.h).unwrap();
+        let prev = Grid::new(vec![None; grid.w * grid.h], grid.w, grid.h).unwrap();
+        Self { start, dist, prev }
+    }
+
+    /// Cost of the cheapest path to `c`, or `None` if it wasn't reached.
+    pub fn distance(&self, c: Coord) -> Option<u32> {
+        self.dist.at(c).copied().flatten()
+    }
+
+    /// The cell visited immediately before `c` on the cheapest path to it.
+    pub fn predecessor(&self, c: Coord) -> Option<Coord> {
+        self.prev.at(c).copied().flatten()
+    }
+
+    pub fn distances(&self) -> &Grid<Option<u32>> {
+        &self.dist
+    }
+
+    pub fn predecessors(&self) -> &Grid<Option<Coord>> {
+        &self.prev
+    }
+
+    /// Iterate over every reached cell and its distance.
+    pub fn reached(&self) -> impl Iterator<Item = (Coord, u32)> + '_ {
+        self.dist
+            .iter_cells()
+            .filter_map(|(c, d)| d.map(|d| (c, d)))
+    }
+
+    /// Reconstruct the path from the start to `target`, inclusive of both
+    /// ends.
+    pub fn path_to(&self, target: Coord) -> Option<Vec<Coord>> {
+        self.distance(target)?;
+
+        let mut path = vec![target];
+        let mut cur = target;
+        while let Some(prev) = self.predecessor(cur) {
+            path.push(prev);
+            cur = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    fn visit(&mut self, c: Coord, d: u32, from: Option<Coord>) {
+        *self.dist.at_mut(c).unwrap() = Some(d);
+        *self.prev.at_mut(c).unwrap() = from;
+    }
+}
+
+/// Unweighted breadth-first search from `start`.
+///
+/// `passable` decides whether a cell may be entered. The start cell is always
+/// considered reachable.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    neighbors: Neighbors,
+    mut passable: impl FnMut(Coord, &T) -> bool,
+) -> SearchResult {
+    let mut result = SearchResult::new(grid, start);
+    if !grid.in_bounds(start) {
+        return result;
+    }
+
+    result.visit(start, 0, None);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(cur) = queue.pop_front() {
+        let d = result.distance(cur).unwrap();
+        for delta in neighbors.deltas() {
+            let next = cur + delta;
+            let Some(cell) = grid.at(next) else {
+                continue;
+            };
+            if result.distance(next).is_some() || !passable(next, cell) {
+                continue;
+            }
+            result.visit(next, d + 1, Some(cur));
+            queue.push_back(next);
+        }
+    }
+
+    result
+}
+
+/// Weighted shortest paths from `start` to every reachable cell.
+///
+/// `cost` returns the cost of entering a cell, or `None` if the cell is
+/// impassable.
+pub fn dijkstra<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    neighbors: Neighbors,
+    cost: impl FnMut(Coord, &T) -> Option<u32>,
+) -> SearchResult {
+    best_first(grid, start, None, neighbors, cost, |_| 0)
+}
+
+/// Weighted shortest path from `start` to `goal`, guided by `heuristic`.
+///
+/// The heuristic must be consistent for the result to be optimal: it never
+/// drops by more than the cost of the step between two neighbours, and is 0
+/// at `goal`. Manhattan distance with costs of at least 1 qualifies. Settled
+/// cells are never re-opened, so a heuristic that is merely admissible can
+/// give a longer path. Search stops as soon as `goal` is settled, so
+/// distances for other cells may be missing or not minimal.
+pub fn astar<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    goal: Coord,
+    neighbors: Neighbors,
+    cost: impl FnMut(Coord, &T) -> Option<u32>,
+    heuristic: impl Fn(Coord) -> u32,
+) -> SearchResult {
+    best_first(grid, start, Some(goal), neighbors, cost, heuristic)
+}
+
+fn best_first<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    goal: Option<Coord>,
+    neighbors: Neighbors,
+    mut cost: impl FnMut(Coord, &T) -> Option<u32>,
+    heuristic: impl Fn(Coord) -> u32,
+) -> SearchResult {
+    let mut result = SearchResult::new(grid, start);
+    let Some(start_idx) = grid.index_of(start) else {
+        return result;
+    };
+
+    let mut settled = vec![false; grid.w * grid.h];
+    result.visit(start, 0, None);
+
+    // Entries are (estimated total cost, cell index).
+    let mut heap = BinaryHeap::from([Reverse((heuristic(start), start_idx))]);
+
+    while let Some(Reverse((_, idx))) = heap.pop() {
+        if settled[idx] {
+            continue;
+        }
+        settled[idx] = true;
+
+        let cur = grid.coord_of(idx);
+        if Some(cur) == goal {
+            break;
+        }
+
+        let d = result.distance(cur).unwrap();
+        for delta in neighbors.deltas() {
+            let next = cur + delta;
+            let Some(next_idx) = grid.index_of(next) else {
+                continue;
+            };
+            if settled[next_idx] {
+                continue;
+            }
+            let Some(step) = cost(next, grid.at(next).unwrap()) else {
+                continue;
+            };
+
+            let candidate = d + step;
+            if result.distance(next).is_some_and(|old| old <= candidate) {
+                continue;
+            }
+            result.visit(next, candidate, Some(cur));
+            heap.push(Reverse((candidate + heuristic(next), next_idx)));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::Neighbors;
+    use crate::grid::{Coord, Grid};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Open,
+        Wall,
+        Mud,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = anyhow::Error;
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Self::Open),
+                '#' => Ok(Self::Wall),
+                '~' => Ok(Self::Mud),
+                c => anyhow::bail!("invalid cell {c}"),
+            }
+        }
+    }
+
+    const MAZE: &str = r#"..#....
+.~#.##.
+.~...#.
+##.#...
+...#.#."#;
+
+    fn cost(_: Coord, c: &Cell) -> Option<u32> {
+        match c {
+            Cell::Open => Some(1),
+            Cell::Mud => Some(5),
+            Cell::Wall => None,
+        }
+    }
+
+    #[test]
+    fn test_bfs() -> anyhow::Result<()> {
+        let grid: Grid<Cell> = MAZE.parse()?;
+        let res = super::bfs(&grid, Coord(0, 0), Neighbors::Cardinal, |_, c| {
+            *c != Cell::Wall
+        });
+
+        assert_eq!(res.distance(Coord(6, 4)), Some(10));
+        assert_eq!(res.distance(Coord(0, 3)), None);
+
+        let path = res.path_to(Coord(6, 4)).unwrap();
+        assert_eq!(path.len(), 11);
+        assert_eq!(path.first(), Some(&Coord(0, 0)));
+        assert_eq!(path.last(), Some(&Coord(6, 4)));
+        for pair in path.windows(2) {
+            assert_eq!(pair[0].manhattan_distance(pair[1]), 1);
+        }
+
+        let diag = super::bfs(&grid, Coord(0, 0), Neighbors::All, |_, c| *c != Cell::Wall);
+        assert_eq!(diag.distance(Coord(6, 4)), Some(6));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() -> anyhow::Result<()> {
+        let grid: Grid<Cell> = MAZE.parse()?;
+        let start = Coord(0, 0);
+        let goal = Coord(6, 4);
+
+        let res = super::dijkstra(&grid, start, Neighbors::Cardinal, cost);
+        // Every route out of the top-left corner crosses one mud cell.
+        assert_eq!(res.distance(Coord(1, 2)), Some(7));
+        assert_eq!(res.distance(goal), Some(14));
+
+        let res = super::astar(&grid, start, goal, Neighbors::Cardinal, cost, |c| {
+            c.manhattan_distance(goal)
+        });
+        assert_eq!(res.distance(goal), Some(14));
+        assert_eq!(res.path_to(goal).map(|p| p.len()), Some(11));
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+use serde::Serialize;
+
+use crate::{
+    answers::{input_hash, AnswerArgs, AnswerStore, Verdict},
+    bench::{bench, DayBench},
+    input::InputArgs,
+};
+
+/// A single day's puzzle.
+pub trait Solution {
+    /// Day of the calendar this solves.
+    const DAY: u32;
+
+    type Input;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> anyhow::Result<impl Display>;
+    fn part2(input: &Self::Input) -> anyhow::Result<impl Display>;
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u32> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(n: u32) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            n => anyhow::bail!("invalid part: {n}"),
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u32>()?.try_into()
+    }
+}
+
+/// The answer to one part of a day, and how long it took to compute.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// The result of running some or all parts of a day against one input.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u32,
+    pub parse_time: Duration,
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Report {
+    pub fn outcome(&self, part: Part) -> Option<&Outcome> {
+        self.outcomes.iter().find(|o| o.part == part)
+    }
+
+    pub fn records(&self) -> Vec<Record> {
+        self.outcomes
+            .iter()
+            .map(|o| Record {
+                day: self.day,
+                part: o.part.number(),
+                answer: o.answer.clone(),
+                parse_time_ns: self.parse_time.as_nanos() as u64,
+                solve_time_ns: o.elapsed.as_nanos() as u64,
+            })
+            .collect()
+    }
+
+    /// Print one JSON object per line for each part in the report.
+    pub fn print_json(&self) -> anyhow::Result<()> {
+        for record in self.records() {
+            println!("{}", serde_json::to_string(&record)?);
+        }
+        Ok(())
+    }
+}
+
+/// One part's result, as printed by `--json`. Every part of a day shares the
+/// same parse time, since the input is only parsed once.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+}
+
+/// A day that couldn't be run, as printed by `--json` in place of its
+/// records.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorRecord {
+    pub day: u32,
+    pub error: String,
+}
+
+impl ErrorRecord {
+    pub fn new(day: u32, error: &anyhow::Error) -> Self {
+        Self {
+            day,
+            error: format!("{error:#}"),
+        }
+    }
+
+    pub fn print_json(&self) -> anyhow::Result<()> {
+        println!("{}", serde_json::to_string(self)?);
+        Ok(())
+    }
+}
+
+/// Parse `input` once and solve each of `parts` against it.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> anyhow::Result<Report> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let outcomes = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed)?.to_string(),
+                Part::Two => S::part2(&parsed)?.to_string(),
+            };
+            Ok(Outcome {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Report {
+        day: S::DAY,
+        parse_time,
+        outcomes,
+    })
+}
+
+/// A type-erased `Solution`, so that every day can be stored in one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    /// The puzzle input compiled into the day's crate.
+    pub input: &'static str,
+    run: fn(&str, &[Part]) -> anyhow::Result<Report>,
+    bench: fn(&str, usize, usize) -> anyhow::Result<DayBench>,
+}
+
+impl Day {
+    pub fn new<S: Solution>(input: &'static str) -> Self {
+        Self {
+            day: S::DAY,
+            input,
+            run: run::<S>,
+            bench: bench::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> anyhow::Result<Report> {
+        (self.run)(input, parts)
+    }
+
+    /// See [`crate::bench::bench`].
+    pub fn bench(&self, input: &str, warmup: usize, iterations: usize) -> anyhow::Result<DayBench> {
+        (self.bench)(input, warmup, iterations)
+    }
+}
+
+/// Solve both parts of one day's puzzle.
+#[derive(Parser)]
+struct DayArgs {
+    /// Print one JSON record per part instead of plain text.
+    #[arg(long)]
+    json: bool,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    answers: AnswerArgs,
+}
+
+/// Entry point for a single day's binary: load the input selected on the
+/// command line, falling back to `embedded`, and print both answers along
+/// with whether they match the recorded ones.
+pub fn main<S: Solution>(embedded: &str) -> anyhow::Result<()> {
+    let args = DayArgs::parse();
+    let loaded = args.input.load(S::DAY, embedded).and_then(|input| {
+        let report = run::<S>(&input.text, &Part::ALL)?;
+        Ok((input, report))
+    });
+    let (input, report) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            // Scripts reading --json output still get a record for the day.
+            if args.json {
+                ErrorRecord::new(S::DAY, &e).print_json()?;
+            }
+            return Err(e);
+        }
+    };
+
+    let path = args.answers.path();
+    let mut store = AnswerStore::load(&path)?;
+    let hash = input_hash(&input.text);
+    let verdicts = store.check_report(&report, &hash);
+    if args.json {
+        report.print_json()?;
+    } else {
+        for (outcome, (_, verdict)) in std::iter::zip(&report.outcomes, &verdicts) {
+            println!("p{}: {} ({verdict})", outcome.part.number(), outcome.answer);
+        }
+    }
+
+    if args.answers.record && store.record_report(&report, &hash) > 0 {
+        store.save(&path)?;
+    }
+
+    let wrong = verdicts
+        .iter()
+        .filter(|(_, v)| matches!(v, Verdict::Wrong { .. }))
+        .count();
+    if wrong > 0 {
+        anyhow::bail!("{wrong} answer(s) differ from {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Day, Part, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+        type Input = Vec<u32>;
+
+        fn parse(s: &str) -> anyhow::Result<Self::Input> {
+            Ok(s.split(',').map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+            Ok(format!("max={}", input.iter().max().unwrap()))
+        }
+    }
+
+    #[test]
+    fn test_run() -> anyhow::Result<()> {
+        let day = Day::new::<Sum>("1,2,3");
+        let report = day.run(day.input, &Part::ALL)?;
+        assert_eq!(report.outcome(Part::One).unwrap().answer, "6");
+        assert_eq!(report.outcome(Part::Two).unwrap().answer, "max=3");
+
+        let report = day.run("4,5", &[Part::Two])?;
+        assert_eq!(report.outcomes.len(), 1);
+        assert!(report.outcome(Part::One).is_none());
+
+        assert!(day.run("4,x", &Part::ALL).is_err());
+
+        let record = &report.records()[0];
+        assert_eq!((record.day, record.part, &*record.answer), (0, 2, "max=5"));
+        let json: serde_json::Value = serde_json::to_value(record)?;
+        for key in ["day", "part", "answer", "parse_time_ns", "solve_time_ns"] {
+            assert!(json.get(key).is_some(), "{key} missing from {json}");
+        }
+        assert_eq!("2".parse::<Part>()?, Part::Two);
+        assert!("3".parse::<Part>().is_err());
+        Ok(())
+    }
+}
@@ -1,8 +1,8 @@
-// auto-generated: "lalrpop 0.22.0"
-// sha3: 58c25660b63c25688aa4a29e5c87aa56d65a40306080f2a8fc4397a90905b585
-use std::str::FromStr;
+// auto-generated: "lalrpop 0.22.2"
+// sha3: 30cdd51c8753202dcab9fd24a557aee0d7ead8910ad476feaafd087d727e2a86
+use crate::input::{ActionError, Input, Line};
 use lalrpop_util::ParseError;
-use crate::input::{Line, Input};
+use std::str::FromStr;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -11,12 +11,12 @@ use self::__lalrpop_util::state_machine as __state_machine;
 extern crate alloc;
 
 #[rustfmt::skip]
-#[allow(explicit_outlives_requirements, non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::never_loop, clippy::match_single_binding, clippy::needless_raw_string_hashes)]
+#[allow(explicit_outlives_requirements, non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::match_single_binding)]
 mod __parse__Input {
 
     use std::str::FromStr;
     use lalrpop_util::ParseError;
-    use crate::input::{Line, Input};
+    use crate::input::{ActionError, Line, Input};
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
@@ -28,10 +28,11 @@ mod __parse__Input {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(&'input str),
-        Variant1(Input),
-        Variant2(Line),
-        Variant3(alloc::vec::Vec<Line>),
-        Variant4(u32),
+        Variant1(usize),
+        Variant2(Input),
+        Variant3(Line),
+        Variant4(alloc::vec::Vec<Line>),
+        Variant5(u32),
     }
     const __ACTION: &[i8] = &[
         // State 0
@@ -43,50 +44,51 @@ mod __parse__Input {
         // State 3
         0,
         // State 4
-        -6,
-        // State 5
         -8,
+        // State 5
+        -10,
         // State 6
-        -7,
+        -9,
         // State 7
-        -3,
+        -5,
     ];
     fn __action(state: i8, integer: usize) -> i8 {
         __ACTION[(state as usize)  + integer]
     }
     const __EOF_ACTION: &[i8] = &[
         // State 0
-        -1,
+        -3,
         // State 1
-        -2,
+        -4,
         // State 2
         0,
         // State 3
-        -9,
+        -11,
         // State 4
-        -6,
-        // State 5
         -8,
+        // State 5
+        -10,
         // State 6
-        -7,
+        -9,
         // State 7
-        -3,
+        -5,
     ];
     fn __goto(state: i8, nt: usize) -> i8 {
         match nt {
-            0 => 3,
-            1 => match state {
+            2 => 3,
+            3 => match state {
                 1 => 6,
                 _ => 4,
             },
-            3 => 1,
-            4 => match state {
+            5 => 1,
+            6 => match state {
                 2 => 7,
                 _ => 2,
             },
             _ => 0,
         }
     }
+    #[allow(clippy::needless_raw_string_hashes)]
     const __TERMINAL: &[&str] = &[
         r###"r#"[0-9]+"#"###,
     ];
@@ -125,7 +127,7 @@ mod __parse__Input {
     where 
     {
         type Location = usize;
-        type Error = &'static str;
+        type Error = ActionError;
         type Token = Token<'input>;
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
@@ -261,48 +263,60 @@ mod __parse__Input {
             }
             1 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 0,
+                    states_to_pop: 0,
+                    nonterminal_produced: 1,
                 }
             }
             2 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 1,
+                    states_to_pop: 0,
+                    nonterminal_produced: 2,
                 }
             }
             3 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 2,
                 }
             }
             4 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 2,
+                    states_to_pop: 2,
+                    nonterminal_produced: 3,
                 }
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 3,
+                    states_to_pop: 0,
+                    nonterminal_produced: 4,
                 }
             }
             6 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 3,
+                    states_to_pop: 1,
+                    nonterminal_produced: 4,
                 }
             }
             7 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 4,
+                    nonterminal_produced: 5,
                 }
             }
-            8 => __state_machine::SimulatedReduce::Accept,
-            _ => panic!("invalid reduction index {}", __reduce_index)
+            8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 5,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 6,
+                }
+            }
+            10 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {__reduce_index}",)
         }
     }
     pub struct InputParser {
@@ -326,7 +340,7 @@ mod __parse__Input {
         >(
             &self,
             input: &'input str,
-        ) -> Result<Input, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        ) -> Result<Input, __lalrpop_util::ParseError<usize, Token<'input>, ActionError>>
         {
             let mut __tokens = self.builder.matcher(input);
             __state_machine::Parser::drive(
@@ -380,7 +394,7 @@ mod __parse__Input {
         __states: &mut alloc::vec::Vec<i8>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<Input,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    ) -> Option<Result<Input,__lalrpop_util::ParseError<usize, Token<'input>, ActionError>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
@@ -405,26 +419,32 @@ mod __parse__Input {
                 __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             7 => {
-                // Num = r#"[0-9]+"# => ActionFn(3);
+                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            8 => {
+                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            9 => {
+                // Num = r#"[0-9]+"# => ActionFn(11);
                 let __sym0 = __pop_Variant0(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
-                let __nt = match super::__action3::<>(input, __sym0) {
+                let __nt = match super::__action11::<>(input, __sym0) {
                     Ok(v) => v,
                     Err(e) => return Some(Err(e)),
                 };
-                __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-                (1, 4)
+                __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+                (1, 6)
             }
-            8 => {
+            10 => {
                 // __Input = Input => ActionFn(0);
-                let __sym0 = __pop_Variant1(__symbols);
+                let __sym0 = __pop_Variant2(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
                 let __nt = super::__action0::<>(input, __sym0);
                 return Some(Ok(__nt));
             }
-            _ => panic!("invalid action code {}", __action)
+            _ => panic!("invalid action code {__action}")
         };
         let __states_len = __states.len();
         __states.truncate(__states_len - __pop_states);
@@ -437,47 +457,58 @@ mod __parse__Input {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant1<
+    fn __pop_Variant2<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Input, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant3<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Line, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant4<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<Line>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant5<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, u32, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -501,10 +532,10 @@ mod __parse__Input {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Input =  => ActionFn(8);
+        // @L =  => ActionFn(5);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action8::<>(input, &__start, &__end);
+        let __nt = super::__action5::<>(input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
         (0, 0)
     }
@@ -517,13 +548,12 @@ mod __parse__Input {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Input = Line+ => ActionFn(9);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action9::<>(input, __sym0);
+        // @R =  => ActionFn(4);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action4::<>(input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 0)
+        (0, 1)
     }
     fn __reduce2<
         'input,
@@ -533,18 +563,51 @@ mod __parse__Input {
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
+    {
+        // Input =  => ActionFn(12);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action12::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 2)
+    }
+    fn __reduce3<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Input = Line+ => ActionFn(13);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action13::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 2)
+    }
+    fn __reduce4<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
     {
         // Line = Num, Num => ActionFn(2);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant4(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action2::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 1)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 3)
     }
-    fn __reduce3<
+    fn __reduce5<
         'input,
     >(
         input: &'input str,
@@ -553,14 +616,14 @@ mod __parse__Input {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Line* =  => ActionFn(4);
+        // Line* =  => ActionFn(6);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action4::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 2)
+        let __nt = super::__action6::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 4)
     }
-    fn __reduce4<
+    fn __reduce6<
         'input,
     >(
         input: &'input str,
@@ -569,15 +632,15 @@ mod __parse__Input {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Line* = Line+ => ActionFn(5);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Line* = Line+ => ActionFn(7);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 2)
+        let __nt = super::__action7::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 4)
     }
-    fn __reduce5<
+    fn __reduce7<
         'input,
     >(
         input: &'input str,
@@ -586,15 +649,15 @@ mod __parse__Input {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Line+ = Line => ActionFn(6);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Line+ = Line => ActionFn(8);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 3)
+        let __nt = super::__action8::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 5)
     }
-    fn __reduce6<
+    fn __reduce8<
         'input,
     >(
         input: &'input str,
@@ -603,15 +666,15 @@ mod __parse__Input {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Line+ = Line+, Line => ActionFn(7);
+        // Line+ = Line+, Line => ActionFn(9);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action7::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        let __nt = super::__action9::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 5)
     }
 }
 #[allow(unused_imports)]
@@ -621,7 +684,7 @@ mod __intern_token {
     #![allow(unused_imports)]
     use std::str::FromStr;
     use lalrpop_util::ParseError;
-    use crate::input::{Line, Input};
+    use crate::input::{ActionError, Line, Input};
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
@@ -630,8 +693,8 @@ mod __intern_token {
     extern crate alloc;
     pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
         let __strs: &[(&str, bool)] = &[
-            ("[0-9]+", false),
-            (r"\s+", true),
+            ("(?-u:[0-9])+", false),
+            (r"(?-u:\s)+", true),
         ];
         __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
     }
@@ -639,170 +702,223 @@ mod __intern_token {
 pub(crate) use self::__lalrpop_util::lexer::Token;
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action0<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Input, usize),
-) -> Input
-{
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action0<'input>(input: &'input str, (_, __0, _): (usize, Input, usize)) -> Input {
     __0
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action1<
-    'input,
->(
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action1<'input>(
     input: &'input str,
     (_, v, _): (usize, alloc::vec::Vec<Line>, usize),
-) -> Input
-{
+) -> Input {
     Input(v)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action2<
-    'input,
->(
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action2<'input>(
     input: &'input str,
     (_, a, _): (usize, u32, usize),
     (_, b, _): (usize, u32, usize),
-) -> Line
-{
+) -> Line {
     Line(a, b)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action3<
-    'input,
->(
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action3<'input>(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Result<u32,__lalrpop_util::ParseError<usize,Token<'input>,&'static str>>
-{
-    u32::from_str(__0)
-        .map_err(|_| ParseError::User {
-            error: "number is too big"
-        })
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Result<u32, __lalrpop_util::ParseError<usize, Token<'input>, ActionError>> {
+    u32::from_str(s).map_err(|_| ParseError::User {
+        error: ActionError {
+            start: l,
+            end: r,
+            message: "number is too big",
+        },
+    })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::needless_lifetimes)]
+fn __action4<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &usize) -> usize {
+    *__lookbehind
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action4<
-    'input,
->(
+#[allow(clippy::needless_lifetimes)]
+fn __action5<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &usize) -> usize {
+    *__lookahead
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action6<'input>(
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> alloc::vec::Vec<Line>
-{
+) -> alloc::vec::Vec<Line> {
     alloc::vec![]
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action5<
-    'input,
->(
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action7<'input>(
     input: &'input str,
     (_, v, _): (usize, alloc::vec::Vec<Line>, usize),
-) -> alloc::vec::Vec<Line>
-{
+) -> alloc::vec::Vec<Line> {
     v
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action6<
-    'input,
->(
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action8<'input>(
     input: &'input str,
     (_, __0, _): (usize, Line, usize),
-) -> alloc::vec::Vec<Line>
-{
+) -> alloc::vec::Vec<Line> {
     alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action7<
-    'input,
->(
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action9<'input>(
     input: &'input str,
     (_, v, _): (usize, alloc::vec::Vec<Line>, usize),
     (_, e, _): (usize, Line, usize),
-) -> alloc::vec::Vec<Line>
-{
-    { let mut v = v; v.push(e); v }
+) -> alloc::vec::Vec<Line> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
-    clippy::just_underscores_and_digits)]
-fn __action8<
-    'input,
->(
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action10<'input>(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Input
-{
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Result<u32, __lalrpop_util::ParseError<usize, Token<'input>, ActionError>> {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action5(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(input, __temp0, __0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action11<'input>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+) -> Result<u32, __lalrpop_util::ParseError<usize, Token<'input>, ActionError>> {
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action4(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(input, __0, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action12<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &usize) -> Input {
     let __start0 = *__lookbehind;
     let __end0 = *__lookahead;
-    let __temp0 = __action4(
-        input,
-        &__start0,
-        &__end0,
-    );
+    let __temp0 = __action6(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
-        input,
-        __temp0,
-    )
+    __action1(input, __temp0)
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
-    clippy::just_underscores_and_digits)]
-fn __action9<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Line>, usize),
-) -> Input
-{
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action13<'input>(input: &'input str, __0: (usize, alloc::vec::Vec<Line>, usize)) -> Input {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action5(
-        input,
-        __0,
-    );
+    let __temp0 = __action7(input, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
-        input,
-        __temp0,
-    )
+    __action1(input, __temp0)
 }
 
 #[allow(clippy::type_complexity, dead_code)]
-pub  trait __ToTriple<'input, >
-{
-    fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
+pub trait __ToTriple<'input> {
+    fn to_triple(
+        self,
+    ) -> Result<
+        (usize, Token<'input>, usize),
+        __lalrpop_util::ParseError<usize, Token<'input>, ActionError>,
+    >;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
-{
-    fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+impl<'input> __ToTriple<'input> for (usize, Token<'input>, usize) {
+    fn to_triple(
+        self,
+    ) -> Result<
+        (usize, Token<'input>, usize),
+        __lalrpop_util::ParseError<usize, Token<'input>, ActionError>,
+    > {
         Ok(self)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str>
-{
-    fn to_triple(self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+impl<'input> __ToTriple<'input> for Result<(usize, Token<'input>, usize), ActionError> {
+    fn to_triple(
+        self,
+    ) -> Result<
+        (usize, Token<'input>, usize),
+        __lalrpop_util::ParseError<usize, Token<'input>, ActionError>,
+    > {
         self.map_err(|error| __lalrpop_util::ParseError::User { error })
     }
 }
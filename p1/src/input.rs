@@ -14,4 +14,51 @@ impl Input {
 }
 
 #[derive(Debug, Clone)]
-pub struct Line(pub i32, pub i32);
+pub struct Line(pub u32, pub u32);
+
+/// An error raised while building the AST, covering the bytes `start..end`.
+#[derive(Debug, Clone, Copy)]
+pub struct ActionError {
+    pub start: usize,
+    pub end: usize,
+    pub message: &'static str,
+}
+
+#[cfg(test)]
+mod test {
+    use common::{parse::ParseError, solution::Solution};
+
+    use crate::Day1;
+
+    fn parse_error(s: &str) -> ParseError {
+        Day1::parse(s).unwrap_err().downcast().unwrap()
+    }
+
+    #[test]
+    fn test_invalid_token() {
+        let e = parse_error("1 2\n3 x4\n");
+        assert_eq!((e.line, e.column, e.width), (2, 3, 1));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: invalid token\n  |\n2 | 3 x4\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_unexpected_eof() {
+        let e = parse_error("1 2\n3\n");
+        assert_eq!((e.line, e.column, e.width), (2, 2, 1));
+        assert!(e.message.starts_with("unexpected end of input, expected"));
+        assert!(e.to_string().ends_with("\n2 | 3\n  |  ^"));
+    }
+
+    #[test]
+    fn test_action_error() {
+        let e = parse_error("1 2\n3 99999999999\n");
+        assert_eq!((e.line, e.column, e.width), (2, 3, 11));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: number is too big\n  |\n2 | 3 99999999999\n  |   ^^^^^^^^^^^"
+        );
+    }
+}
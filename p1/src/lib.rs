@@ -0,0 +1,87 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{parse::ParseError, solution::Solution};
+
+mod grammar;
+mod input;
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = input::Input;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        grammar::InputParser::new()
+            .parse(s)
+            .map_err(|e| diagnostic(s, e).into())
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        part2(input.clone())
+    }
+}
+
+type GrammarError<T> = lalrpop_util::ParseError<usize, T, input::ActionError>;
+
+fn diagnostic<T: Display>(s: &str, e: GrammarError<T>) -> ParseError {
+    use lalrpop_util::ParseError as E;
+
+    let expected = |expected: Vec<String>| match expected.len() {
+        0 => String::new(),
+        _ => format!(", expected {}", expected.join(" or ")),
+    };
+    match e {
+        E::InvalidToken { location } => ParseError::at(s, location..location + 1, "invalid token"),
+        E::UnrecognizedEof {
+            location,
+            expected: e,
+        } => ParseError::at(
+            s,
+            location..location,
+            format!("unexpected end of input{}", expected(e)),
+        ),
+        E::UnrecognizedToken {
+            token: (l, t, r),
+            expected: e,
+        } => ParseError::at(s, l..r, format!("unexpected `{t}`{}", expected(e))),
+        E::ExtraToken { token: (l, t, r) } => ParseError::at(s, l..r, format!("unexpected `{t}`")),
+        E::User { error } => ParseError::at(s, error.start..error.end, error.message),
+    }
+}
+
+fn part1(input: input::Input) -> anyhow::Result<u32> {
+    let (mut left, mut right) = input.split();
+    left.sort();
+    right.sort();
+
+    let res = std::iter::zip(left, right).fold(0, |acc, (a, b)| acc + a.abs_diff(b));
+    Ok(res)
+}
+
+fn part2(input: input::Input) -> anyhow::Result<u32> {
+    let (left, right) = input.split();
+
+    let counts = {
+        let mut m = HashMap::new();
+        for n in right {
+            let entry: &mut u32 = m.entry(n).or_default();
+            *entry += 1;
+        }
+        m
+    };
+
+    let res = left.iter().fold(0, |acc, n| {
+        acc + n * counts.get(n).copied().unwrap_or_default()
+    });
+
+    Ok(res)
+}
+
+common::example_tests!(Day1, include_str!("../README.md"));
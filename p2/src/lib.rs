@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use common::{parse, solution::Solution};
+use safety::Rules;
+
+pub mod safety;
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Input;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        s.parse()
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.part1())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.part2())
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    lines: Vec<Line>,
+}
+
+impl Input {
+    pub fn part1(&self) -> u32 {
+        self.count_safe(&Rules::default(), 0)
+    }
+
+    pub fn part2(&self) -> u32 {
+        self.count_safe(&Rules::default(), 1)
+    }
+
+    /// The number of reports that are safe under `rules` after removing at
+    /// most `k` levels.
+    pub fn count_safe(&self, rules: &Rules, k: usize) -> u32 {
+        self.lines
+            .iter()
+            .map(|l| u32::from(l.is_safe_with_removals(rules, k)))
+            .sum()
+    }
+
+    /// How many levels each report needs removed to be safe under `rules`,
+    /// or `None` if it can't be made safe.
+    pub fn removals_needed(&self, rules: &Rules) -> Vec<Option<usize>> {
+        self.lines
+            .iter()
+            .map(|l| l.removals_needed(rules))
+            .collect()
+    }
+}
+
+impl FromStr for Input {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = parse::lines(s)
+            .map(|l| {
+                if l.text.trim().is_empty() {
+                    return Err(l.error_at(0, "empty report").into());
+                }
+                Ok(Line(l.list(" ")?))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { lines })
+    }
+}
+
+#[derive(Debug)]
+struct Line(Vec<u32>);
+
+impl Line {
+    fn is_safe_with_removals(&self, rules: &Rules, k: usize) -> bool {
+        safety::min_removals(&self.0, rules, k).is_some()
+    }
+
+    fn removals_needed(&self, rules: &Rules) -> Option<usize> {
+        safety::min_removals(&self.0, rules, self.0.len())
+    }
+}
+
+common::example_tests!(Day2, include_str!("../README.md"));
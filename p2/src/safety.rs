@@ -0,0 +1,240 @@
+//! Deciding whether a report is safe, possibly after removing some levels.
+
+/// What counts as a safe step between adjacent levels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Smallest allowed change between adjacent levels, other than a
+    /// plateau.
+    pub min_step: u32,
+    /// Largest allowed change between adjacent levels.
+    pub max_step: u32,
+    /// Whether every step has to go the same way. Plateaus, if allowed, fit
+    /// either way.
+    pub monotonic: bool,
+    /// Whether adjacent levels may be equal.
+    pub allow_plateaus: bool,
+}
+
+impl Default for Rules {
+    /// The puzzle's rules: strictly increasing or decreasing by 1 to 3.
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            monotonic: true,
+            allow_plateaus: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+}
+
+impl Rules {
+    fn allows(&self, a: u32, b: u32, direction: Direction) -> bool {
+        if a == b {
+            return self.allow_plateaus;
+        }
+        let going = if a < b {
+            Direction::Up
+        } else {
+            Direction::Down
+        };
+        let diff = a.abs_diff(b);
+        (self.min_step..=self.max_step).contains(&diff) && (!self.monotonic || going == direction)
+    }
+}
+
+/// The fewest levels that have to be removed to make `levels` safe, if that
+/// can be done by removing at most `limit`. A report needs at least two
+/// levels to be safe.
+///
+/// Adjacent kept levels can be at most `limit + 1` apart, so each level only
+/// has to look that far back for the level before it. That takes
+/// O(n · min(limit, n)) time: linear in the length of the report for a fixed
+/// `limit`.
+pub fn min_removals(levels: &[u32], rules: &Rules, limit: usize) -> Option<usize> {
+    let directions: &[Direction] = if rules.monotonic {
+        &[Direction::Up, Direction::Down]
+    } else {
+        &[Direction::Up]
+    };
+    directions
+        .iter()
+        .filter_map(|&direction| min_removals_going(levels, rules, direction, limit))
+        .min()
+}
+
+/// [`min_removals`] for steps going in `direction`, if the rules are
+/// monotonic.
+fn min_removals_going(
+    levels: &[u32],
+    rules: &Rules,
+    direction: Direction,
+    limit: usize,
+) -> Option<usize> {
+    let n = levels.len();
+    // removed[i]: the fewest levels removed before `i` in a safe run of at
+    // least two levels that ends by keeping `i`.
+    let mut removed: Vec<Option<usize>> = vec![None; n];
+    let mut best = None;
+    for i in 0..n {
+        let first = i.saturating_sub(limit.saturating_add(1));
+        for j in first..i {
+            let gap = i - j - 1;
+            // Keeping `j` either starts the run, removing everything before
+            // it, or extends a run that already ends there.
+            let before = removed[j].map_or(j, |r| r.min(j));
+            let total = before + gap;
+            if total <= limit
+                && rules.allows(levels[j], levels[i], direction)
+                && removed[i].is_none_or(|r| total < r)
+            {
+                removed[i] = Some(total);
+            }
+        }
+        if let Some(r) = removed[i] {
+            let total = r + (n - 1 - i);
+            if total <= limit && best.is_none_or(|b| total < b) {
+                best = Some(total);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use common::parse::ParseError;
+
+    use super::{min_removals, Direction, Rules};
+    use crate::Input;
+
+    const EXAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+    /// Try every way of removing up to `limit` levels.
+    fn brute_force(levels: &[u32], rules: &Rules, limit: usize) -> Option<usize> {
+        let n = levels.len();
+        (0u32..1 << n)
+            .filter(|mask| (mask.count_ones() as usize) <= limit)
+            .filter(|&mask| {
+                let kept: Vec<u32> = (0..n)
+                    .filter(|i| mask & (1 << i) == 0)
+                    .map(|i| levels[i])
+                    .collect();
+                kept.len() >= 2
+                    && [Direction::Up, Direction::Down]
+                        .iter()
+                        .any(|&d| kept.windows(2).all(|w| rules.allows(w[0], w[1], d)))
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+    }
+
+    #[test]
+    fn test_min_removals() {
+        let rules = Rules::default();
+        assert_eq!(min_removals(&[7, 6, 4, 2, 1], &rules, 0), Some(0));
+        assert_eq!(min_removals(&[1, 2, 7, 8, 9], &rules, 1), None);
+        assert_eq!(min_removals(&[1, 2, 7, 8, 9], &rules, 3), Some(2));
+        assert_eq!(min_removals(&[8, 6, 4, 4, 1], &rules, 1), Some(1));
+        assert_eq!(min_removals(&[5], &rules, 5), None);
+        assert_eq!(min_removals(&[1, 2, 7, 8, 9], &rules, usize::MAX), Some(2));
+
+        let relaxed = Rules {
+            monotonic: false,
+            allow_plateaus: true,
+            ..Rules::default()
+        };
+        assert_eq!(min_removals(&[1, 3, 3, 2, 4], &relaxed, 0), Some(0));
+        let wide = Rules {
+            min_step: 2,
+            max_step: 10,
+            ..Rules::default()
+        };
+        assert_eq!(min_removals(&[1, 2, 7, 8, 9], &wide, 5), Some(2));
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let all_rules = [
+            Rules::default(),
+            Rules {
+                monotonic: false,
+                ..Rules::default()
+            },
+            Rules {
+                allow_plateaus: true,
+                ..Rules::default()
+            },
+            Rules {
+                min_step: 2,
+                max_step: 4,
+                ..Rules::default()
+            },
+            Rules {
+                min_step: 0,
+                max_step: u32::MAX,
+                monotonic: false,
+                allow_plateaus: false,
+            },
+            Rules {
+                min_step: 1,
+                max_step: 2,
+                monotonic: false,
+                allow_plateaus: true,
+            },
+        ];
+        // A small linear congruential generator, so the reports are the
+        // same every run.
+        let mut seed = 12345u32;
+        let mut next = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) % 8
+        };
+        for _ in 0..300 {
+            let len = next() as usize + 1;
+            let levels: Vec<u32> = (0..len).map(|_| next()).collect();
+            for rules in &all_rules {
+                for limit in [0, 1, 2, 3, usize::MAX] {
+                    assert_eq!(
+                        min_removals(&levels, rules, limit),
+                        brute_force(&levels, rules, limit),
+                        "{levels:?}, {rules:?}, limit = {limit}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_input() -> anyhow::Result<()> {
+        let input: Input = EXAMPLE.parse()?;
+        let rules = Rules::default();
+        assert_eq!(
+            input.removals_needed(&rules),
+            [Some(0), Some(2), Some(2), Some(1), Some(1), Some(0)]
+        );
+        assert_eq!(input.count_safe(&rules, 0), 2);
+        assert_eq!(input.count_safe(&rules, 1), 4);
+        assert_eq!(input.count_safe(&rules, usize::MAX), 6);
+
+        let plateaus = Rules {
+            allow_plateaus: true,
+            ..Rules::default()
+        };
+        assert_eq!(input.count_safe(&plateaus, 0), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_blank_line() {
+        let err = "7 6 4 2 1\n\n1 2 7 8 9\n".parse::<Input>().unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "empty report");
+    }
+}
@@ -0,0 +1,218 @@
+//! A diagnostic view of how the input was read: which fragments ran, which
+//! were switched off, and which only looked like instructions.
+
+use std::{fmt::Write, ops::Range};
+
+use crate::{
+    lexer::{self, Token},
+    num::{Arithmetic, Number},
+    vm::{Instruction, InstructionSet, Machine},
+};
+
+/// A fragment of the input, and what the machine made of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fragment<N> {
+    /// An instruction that ran, and how much it changed the total by.
+    Accepted {
+        instruction: Instruction<N>,
+        contribution: N,
+    },
+    /// An instruction that had no effect because the machine was disabled.
+    Disabled { instruction: Instruction<N> },
+    /// An instruction without arguments, like `do()` or `don't()`, which
+    /// can only switch the machine on or off. `enabled` is the machine's
+    /// state afterwards.
+    Control {
+        instruction: Instruction<N>,
+        enabled: bool,
+    },
+    /// Text that starts like an instruction but isn't well-formed.
+    NearMiss { span: Range<usize> },
+}
+
+impl<N> Fragment<N> {
+    /// The byte range of the fragment in the input.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            Fragment::Accepted { instruction, .. }
+            | Fragment::Disabled { instruction }
+            | Fragment::Control { instruction, .. } => {
+                instruction.offset..instruction.offset + instruction.len
+            }
+            Fragment::NearMiss { span } => span.clone(),
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Fragment::Accepted { .. } => "accepted",
+            Fragment::Disabled { .. } => "disabled",
+            Fragment::Control { .. } => "control",
+            Fragment::NearMiss { .. } => "near miss",
+        }
+    }
+}
+
+/// Run `s` with checked arithmetic, classifying every instruction and near
+/// miss that `set` knows about.
+pub fn annotate<N: Number>(s: &str, set: &InstructionSet<N>) -> anyhow::Result<Vec<Fragment<N>>> {
+    let mut machine = Machine::new(Arithmetic::Checked);
+    let mut fragments = Vec::new();
+    for token in lexer::scan(s, set)? {
+        let instruction = match token {
+            Token::Instruction(instruction) => instruction,
+            Token::NearMiss(span) => {
+                fragments.push(Fragment::NearMiss { span });
+                continue;
+            }
+        };
+        let before = machine.clone();
+        machine.step(set, &instruction)?;
+        fragments.push(if instruction.args.is_empty() {
+            Fragment::Control {
+                instruction,
+                enabled: machine.enabled,
+            }
+        } else if !before.enabled {
+            Fragment::Disabled { instruction }
+        } else {
+            Fragment::Accepted {
+                contribution: machine.acc.wrapping_sub(&before.acc),
+                instruction,
+            }
+        });
+    }
+    Ok(fragments)
+}
+
+/// How [`render`] marks up fragments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Green for accepted, red for disabled, blue for control and yellow for
+    /// near misses.
+    Ansi,
+    /// `[+...]` for accepted, `[-...]` for disabled, `[*...]` for control
+    /// and `[?...]` for near misses, for when colour isn't available.
+    Brackets,
+}
+
+/// Reprint `s` with `fragments` highlighted.
+pub fn render<N>(s: &str, fragments: &[Fragment<N>], style: Style) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut pos = 0;
+    for fragment in fragments {
+        let span = fragment.span();
+        out.push_str(&s[pos..span.start]);
+        let (open, close) = match (style, fragment) {
+            (Style::Ansi, Fragment::Accepted { .. }) => ("\x1b[32m", "\x1b[0m"),
+            (Style::Ansi, Fragment::Disabled { .. }) => ("\x1b[31m", "\x1b[0m"),
+            (Style::Ansi, Fragment::Control { .. }) => ("\x1b[34m", "\x1b[0m"),
+            (Style::Ansi, Fragment::NearMiss { .. }) => ("\x1b[33m", "\x1b[0m"),
+            (Style::Brackets, Fragment::Accepted { .. }) => ("[+", "]"),
+            (Style::Brackets, Fragment::Disabled { .. }) => ("[-", "]"),
+            (Style::Brackets, Fragment::Control { .. }) => ("[*", "]"),
+            (Style::Brackets, Fragment::NearMiss { .. }) => ("[?", "]"),
+        };
+        out.push_str(open);
+        out.push_str(&s[span.clone()]);
+        out.push_str(close);
+        pos = span.end;
+    }
+    out.push_str(&s[pos..]);
+    out
+}
+
+/// One line per fragment with its byte offset, its text and, for accepted
+/// instructions, its contribution to the total. Control instructions show
+/// whether they leave the machine on or off.
+pub fn listing<N: Number>(s: &str, fragments: &[Fragment<N>]) -> String {
+    let mut out = String::new();
+    for fragment in fragments {
+        let span = fragment.span();
+        let text = &s[span.clone()];
+        write!(
+            out,
+            "{:>8}  {:<9}  {text:<20}",
+            span.start,
+            fragment.label()
+        )
+        .unwrap();
+        match fragment {
+            Fragment::Accepted { contribution, .. } => write!(out, "  +{contribution}").unwrap(),
+            Fragment::Control { enabled, .. } => {
+                out.push_str(if *enabled { "  on" } else { "  off" })
+            }
+            _ => {}
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{annotate, listing, render, Fragment, Style};
+    use crate::{vm::InstructionSet, Program};
+
+    const EXAMPLE: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn test_annotate() -> anyhow::Result<()> {
+        let set = InstructionSet::<u32>::part2();
+        let fragments = annotate(EXAMPLE, &set)?;
+        assert_eq!(
+            render(EXAMPLE, &fragments, Style::Brackets),
+            "x[+mul(2,4)]&[?mul[3,7]]!^[*don't()]_[-mul(5,5)]+[?mul(32,64]]\
+             ([-mul(11,8)]un[*do()]?[+mul(8,5)])"
+        );
+
+        let total: u32 = fragments
+            .iter()
+            .filter_map(|f| match f {
+                Fragment::Accepted { contribution, .. } => Some(contribution),
+                _ => None,
+            })
+            .sum();
+        assert_eq!(total, EXAMPLE.parse::<Program<u32>>()?.eval_p2()?);
+
+        let listing = listing(EXAMPLE, &fragments);
+        let lines: Vec<&str> = listing.lines().map(str::trim_end).collect();
+        assert_eq!(lines[0], "       1  accepted   mul(2,4)              +8");
+        assert_eq!(lines[1], "      10  near miss  mul[3,7]");
+        assert_eq!(lines[2], "      20  control    don't()               off");
+        assert_eq!(lines[3], "      28  disabled   mul(5,5)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_control() -> anyhow::Result<()> {
+        let set = InstructionSet::<u32>::part2();
+        let input = "don't()don't()mul(2,3)do()mul(1,1)";
+        let fragments = annotate(input, &set)?;
+        assert_eq!(
+            render(input, &fragments, Style::Brackets),
+            "[*don't()][*don't()][-mul(2,3)][*do()][+mul(1,1)]"
+        );
+        let states: Vec<bool> = fragments
+            .iter()
+            .filter_map(|f| match f {
+                Fragment::Control { enabled, .. } => Some(*enabled),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(states, [false, false, true]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ansi() -> anyhow::Result<()> {
+        let set = InstructionSet::<u32>::part1();
+        let fragments = annotate("don't()mul(1,2)", &set)?;
+        assert_eq!(
+            render("don't()mul(1,2)", &fragments, Style::Ansi),
+            "don't()\x1b[32mmul(1,2)\x1b[0m"
+        );
+        Ok(())
+    }
+}
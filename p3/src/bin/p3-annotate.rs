@@ -0,0 +1,47 @@
+use std::{io::IsTerminal, path::PathBuf};
+
+use clap::Parser;
+use p3::{
+    annotate::{annotate, listing, render, Style},
+    vm::InstructionSet,
+};
+
+/// Reprint the input with the instructions that were accepted, disabled,
+/// switched the machine on or off, or nearly matched highlighted, followed
+/// by a list of them.
+#[derive(Parser)]
+struct Args {
+    /// File to read. Uses the bundled input if omitted.
+    path: Option<PathBuf>,
+
+    /// Which part's instruction set to use.
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: u32,
+
+    /// Mark fragments with brackets instead of colour. The default when
+    /// stdout isn't a terminal.
+    #[arg(long)]
+    brackets: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let input = match &args.path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => p3::INPUT.to_string(),
+    };
+    let set = match args.part {
+        1 => InstructionSet::<u64>::part1(),
+        _ => InstructionSet::part2(),
+    };
+    let style = if args.brackets || !std::io::stdout().is_terminal() {
+        Style::Brackets
+    } else {
+        Style::Ansi
+    };
+
+    let fragments = annotate(&input, &set)?;
+    println!("{}", render(&input, &fragments, style));
+    print!("{}", listing(&input, &fragments));
+    Ok(())
+}
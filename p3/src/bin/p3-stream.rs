@@ -0,0 +1,66 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::PathBuf,
+};
+
+use clap::{Parser, ValueEnum};
+use num_bigint::BigInt;
+use p3::num::{Arithmetic, Number};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum NumType {
+    U32,
+    U64,
+    U128,
+    /// Arbitrary precision.
+    Big,
+}
+
+/// Evaluate a memory dump too large to load at once, reading it in chunks.
+#[derive(Parser)]
+struct Args {
+    /// File to read. Reads stdin if omitted.
+    path: Option<PathBuf>,
+
+    /// Numeric type to compute with.
+    #[arg(long = "type", value_enum, default_value = "u64")]
+    num_type: NumType,
+
+    /// Wrap around on overflow instead of failing.
+    #[arg(long)]
+    wrapping: bool,
+}
+
+fn eval<N: Number>(reader: impl Read, mode: Arithmetic) -> anyhow::Result<()> {
+    let totals = p3::stream::eval_reader::<N>(reader, mode)?;
+    for (name, total) in [("p1", &totals.part1), ("p2", &totals.part2)] {
+        match total {
+            Ok(n) => println!("{name}: {n}"),
+            Err(e) => eprintln!("{name}: {e}"),
+        }
+    }
+    if totals.part1.is_err() || totals.part2.is_err() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let reader: Box<dyn Read> = match &args.path {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(std::io::stdin().lock()),
+    };
+    let mode = if args.wrapping {
+        Arithmetic::Wrapping
+    } else {
+        Arithmetic::Checked
+    };
+    match args.num_type {
+        NumType::U32 => eval::<u32>(reader, mode),
+        NumType::U64 => eval::<u64>(reader, mode),
+        NumType::U128 => eval::<u128>(reader, mode),
+        NumType::Big => eval::<BigInt>(reader, mode),
+    }
+}
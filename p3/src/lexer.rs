@@ -0,0 +1,180 @@
+use std::ops::Range;
+
+use common::parse::ParseError;
+
+use crate::{
+    num::Number,
+    vm::{Instruction, InstructionSet, Opcode},
+};
+
+/// A fragment of the input that the lexer recognized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<N> {
+    Instruction(Instruction<N>),
+    /// Text that starts like an instruction but isn't well-formed, such as
+    /// `mul[3,7]` or `mul(32,64]`.
+    NearMiss(Range<usize>),
+}
+
+/// Find every well-formed instruction in `s`, skipping over anything else.
+/// Matching is leftmost-first: at each position the opcodes of `set` are
+/// tried in order, and a match consumes its text so that matches never
+/// overlap.
+pub fn tokenize<N: Number>(
+    s: &str,
+    set: &InstructionSet<N>,
+) -> Result<Vec<Instruction<N>>, ParseError> {
+    Ok(scan(s, set)?
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Instruction(instr) => Some(instr),
+            Token::NearMiss(_) => None,
+        })
+        .collect())
+}
+
+/// Like [`tokenize`], but also report near misses.
+pub fn scan<N: Number>(s: &str, set: &InstructionSet<N>) -> Result<Vec<Token<N>>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < s.len() {
+        if let Some(instr) = set.opcodes().iter().find_map(|op| match_at(s, i, op)) {
+            let instr = instr?;
+            i += instr.len;
+            tokens.push(Token::Instruction(instr));
+        } else if let Some(span) = set.opcodes().iter().find_map(|op| near_miss(s, i, op)) {
+            i = span.end;
+            tokens.push(Token::NearMiss(span));
+        } else {
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+/// The text at byte `start` of `s` that looks like a botched use of `op`:
+/// its name and an opening bracket of any kind, then whatever numbers and
+/// commas follow, and finally the punctuation that broke the match.
+fn near_miss<N>(s: &str, start: usize, op: &Opcode<N>) -> Option<Range<usize>> {
+    let bytes = s.as_bytes();
+    let mut i = start + op.name.len();
+    if !bytes[start..].starts_with(op.name.as_bytes())
+        || !matches!(bytes.get(i), Some(b'(' | b'[' | b'{' | b'<'))
+    {
+        return None;
+    }
+    i += 1;
+    while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b',' || bytes[i] == b' ') {
+        i += 1;
+    }
+    while bytes[i - 1] == b' ' {
+        i -= 1;
+    }
+    if bytes.get(i).is_some_and(u8::is_ascii_punctuation) {
+        i += 1;
+    }
+    Some(start..i)
+}
+
+/// Match `op` at byte `start` of `s`, i.e. `name(` followed by `op.arity`
+/// comma-separated numbers and `)`.
+fn match_at<N: Number>(
+    s: &str,
+    start: usize,
+    op: &Opcode<N>,
+) -> Option<Result<Instruction<N>, ParseError>> {
+    let bytes = s.as_bytes();
+    let mut i = start;
+    let expect = |token: &[u8], i: &mut usize| {
+        let found = bytes[*i..].starts_with(token);
+        if found {
+            *i += token.len();
+        }
+        found
+    };
+
+    if !expect(op.name.as_bytes(), &mut i) || !expect(b"(", &mut i) {
+        return None;
+    }
+    let mut spans = Vec::with_capacity(op.arity);
+    for n in 0..op.arity {
+        if n > 0 && !expect(b",", &mut i) {
+            return None;
+        }
+        let digits = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        spans.push(i..i + digits);
+        i += digits;
+    }
+    if !expect(b")", &mut i) {
+        return None;
+    }
+
+    let args = spans
+        .into_iter()
+        .map(|span| {
+            N::from_digits(&s[span.clone()])
+                .ok_or_else(|| ParseError::at(s, span, "number is too big"))
+        })
+        .collect::<Result<_, _>>();
+    Some(args.map(|args| Instruction {
+        name: op.name,
+        args,
+        offset: start,
+        len: i - start,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{scan, tokenize, Token};
+    use crate::vm::InstructionSet;
+
+    #[test]
+    fn test_tokenize() -> anyhow::Result<()> {
+        let set = InstructionSet::<u32>::part2();
+        let instrs = tokenize("mumul(1,23)mul(4,5,6)mul( 1,2)don't()do()mul(7,8", &set)?;
+        let found: Vec<(String, usize)> =
+            instrs.iter().map(|i| (i.to_string(), i.offset)).collect();
+        assert_eq!(
+            found,
+            [
+                ("mul(1,23)".to_string(), 2),
+                ("don't()".to_string(), 30),
+                ("do()".to_string(), 37),
+            ]
+        );
+
+        let err = tokenize("xmul(2,99999999999)", &set).unwrap_err();
+        assert_eq!((err.column, err.width), (8, 11));
+        Ok(())
+    }
+
+    #[test]
+    fn test_near_misses() -> anyhow::Result<()> {
+        let input = "mul[3,7]!mul(32,64]mul(4*mul ( 2 , 4 )mul(6, mul(1,2)don't{}do_not";
+        let tokens = scan(input, &InstructionSet::<u32>::part2())?;
+        let found: Vec<&str> = tokens
+            .iter()
+            .map(|token| match token {
+                Token::Instruction(instr) => &input[instr.offset..instr.offset + instr.len],
+                Token::NearMiss(span) => &input[span.clone()],
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "mul[3,7]",
+                "mul(32,64]",
+                "mul(4*",
+                "mul(6,",
+                "mul(1,2)",
+                "don't{}"
+            ]
+        );
+        assert!(matches!(tokens[4], Token::Instruction(_)));
+        Ok(())
+    }
+}
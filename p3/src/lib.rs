@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use common::{parse::ParseError, solution::Solution};
+use num::{Arithmetic, Number};
+use vm::{Instruction, InstructionSet, Machine, OverflowError, Step};
+
+pub mod annotate;
+mod lexer;
+pub mod num;
+pub mod stream;
+pub mod vm;
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Program<u64>;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        Ok(s.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.eval_p1()?)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.eval_p2()?)
+    }
+}
+
+/// The instructions found in the input, with arguments and results of type
+/// `N`.
+pub struct Program<N> {
+    instrs: Vec<Instruction<N>>,
+}
+
+impl<N: Number> Program<N> {
+    /// Decode every instruction of `set` found in `s`.
+    pub fn parse_with(s: &str, set: &InstructionSet<N>) -> Result<Self, ParseError> {
+        Ok(Self {
+            instrs: lexer::tokenize(s, set)?,
+        })
+    }
+
+    pub fn instructions(&self) -> &[Instruction<N>] {
+        &self.instrs
+    }
+
+    /// Run every instruction on a fresh machine that understands `set`.
+    pub fn run(
+        &self,
+        set: &InstructionSet<N>,
+        mode: Arithmetic,
+    ) -> Result<Machine<N>, OverflowError> {
+        let mut machine = Machine::new(mode);
+        for instr in &self.instrs {
+            machine.step(set, instr)?;
+        }
+        Ok(machine)
+    }
+
+    /// Run the program one instruction at a time with checked arithmetic,
+    /// yielding the machine's state before and after each. Stops after the
+    /// first overflow.
+    pub fn trace<'a>(
+        &'a self,
+        set: &'a InstructionSet<N>,
+    ) -> impl Iterator<Item = Result<Step<'a, N>, OverflowError>> + 'a {
+        let mut machine = Machine::default();
+        let mut failed = false;
+        self.instrs.iter().map_while(move |instruction| {
+            if failed {
+                return None;
+            }
+            let before = machine.clone();
+            let step = match machine.step(set, instruction) {
+                Ok(executed) => Ok(Step {
+                    instruction,
+                    executed,
+                    before,
+                    after: machine.clone(),
+                }),
+                Err(e) => {
+                    failed = true;
+                    Err(e)
+                }
+            };
+            Some(step)
+        })
+    }
+
+    pub fn eval_p1(&self) -> Result<N, OverflowError> {
+        Ok(self.run(&InstructionSet::part1(), Arithmetic::Checked)?.acc)
+    }
+
+    pub fn eval_p2(&self) -> Result<N, OverflowError> {
+        Ok(self.run(&InstructionSet::part2(), Arithmetic::Checked)?.acc)
+    }
+}
+
+impl<N: Number> FromStr for Program<N> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &InstructionSet::part2())
+    }
+}
+
+common::example_tests!(Day3, include_str!("../README.md"));
@@ -0,0 +1,140 @@
+//! The numeric types the machine can compute with.
+
+use std::fmt;
+
+use num_bigint::BigInt;
+
+/// How the machine handles results that don't fit in its numeric type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Fail with an [`Overflow`].
+    #[default]
+    Checked,
+    /// Wrap around, like release builds do for plain integer arithmetic.
+    Wrapping,
+}
+
+/// Marker error for an arithmetic result that didn't fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+/// A number the machine can use for arguments and its accumulator.
+pub trait Number: Clone + Default + PartialEq + fmt::Debug + fmt::Display {
+    /// Parse a non-empty string of ASCII digits, or `None` if the value
+    /// doesn't fit.
+    fn from_digits(digits: &str) -> Option<Self>;
+
+    /// `self * 10 + digit`, or `None` if that doesn't fit. For building up a
+    /// number one digit at a time.
+    fn push_digit(&self, digit: u8) -> Option<Self>;
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+
+    fn wrapping_add(&self, rhs: &Self) -> Self;
+    fn wrapping_sub(&self, rhs: &Self) -> Self;
+    fn wrapping_mul(&self, rhs: &Self) -> Self;
+
+    /// `self + rhs` under `mode`.
+    fn add(&self, rhs: &Self, mode: Arithmetic) -> Result<Self, Overflow> {
+        match mode {
+            Arithmetic::Checked => self.checked_add(rhs).ok_or(Overflow),
+            Arithmetic::Wrapping => Ok(self.wrapping_add(rhs)),
+        }
+    }
+
+    /// `self - rhs` under `mode`.
+    fn sub(&self, rhs: &Self, mode: Arithmetic) -> Result<Self, Overflow> {
+        match mode {
+            Arithmetic::Checked => self.checked_sub(rhs).ok_or(Overflow),
+            Arithmetic::Wrapping => Ok(self.wrapping_sub(rhs)),
+        }
+    }
+
+    /// `self * rhs` under `mode`.
+    fn mul(&self, rhs: &Self, mode: Arithmetic) -> Result<Self, Overflow> {
+        match mode {
+            Arithmetic::Checked => self.checked_mul(rhs).ok_or(Overflow),
+            Arithmetic::Wrapping => Ok(self.wrapping_mul(rhs)),
+        }
+    }
+}
+
+macro_rules! impl_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn from_digits(digits: &str) -> Option<Self> {
+                    digits.parse().ok()
+                }
+
+                fn push_digit(&self, digit: u8) -> Option<Self> {
+                    <$t>::checked_mul(*self, 10)?.checked_add(<$t>::from(digit))
+                }
+
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *rhs)
+                }
+
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *rhs)
+                }
+
+                fn wrapping_add(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_add(*self, *rhs)
+                }
+
+                fn wrapping_sub(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_sub(*self, *rhs)
+                }
+
+                fn wrapping_mul(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_mul(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive!(u32, u64, u128);
+
+/// Arbitrary precision, so nothing ever overflows and both modes agree.
+/// Signed, so that subtraction can't underflow either.
+impl Number for BigInt {
+    fn from_digits(digits: &str) -> Option<Self> {
+        digits.parse().ok()
+    }
+
+    fn push_digit(&self, digit: u8) -> Option<Self> {
+        Some(self * 10u32 + digit)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
@@ -0,0 +1,307 @@
+//! Evaluation of `mul`, `do` and `don't` over a byte stream in constant
+//! memory, for inputs too large to load at once.
+//!
+//! This gives the same answers as [`crate::Program`] with the standard
+//! instruction sets, without decoding a list of instructions first. Every
+//! token starts with `m` or `d`, and neither letter can appear after the
+//! first byte of a token, so when a partial match fails the byte that broke
+//! it is the only place a new match could begin. That means nothing ever has
+//! to be buffered.
+
+use std::io::Read;
+
+use crate::{
+    num::{Arithmetic, Number, Overflow},
+    vm::OverflowError,
+};
+
+/// Bytes read from the underlying reader at a time.
+const CHUNK: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum State<N> {
+    Start,
+    M,
+    Mu,
+    Mul,
+    /// After `mul(`, waiting for the first digit.
+    MulOpen,
+    /// In the first number. `None` once it no longer fits in an `N`.
+    First(Option<N>),
+    /// After the comma, waiting for the second number's first digit.
+    Comma(Option<N>),
+    Second(Option<N>, Option<N>),
+    D,
+    Do,
+    DoOpen,
+    Don,
+    DonQuote,
+    Dont,
+    DontOpen,
+}
+
+enum Event<N> {
+    Mul(Option<N>, Option<N>),
+    Do,
+    Dont,
+}
+
+fn digit<N: Number>(n: Option<N>, b: u8) -> Option<N> {
+    n?.push_digit(b - b'0')
+}
+
+/// The state after reading `b` in `state`, and the instruction that `b`
+/// completes, if any. `None` if `b` can't continue the current match.
+fn transition<N: Number>(state: State<N>, b: u8) -> Option<(State<N>, Option<Event<N>>)> {
+    use State::*;
+
+    let next = match (state, b) {
+        (Start, b'm') => M,
+        (Start, b'd') => D,
+        (M, b'u') => Mu,
+        (Mu, b'l') => Mul,
+        (Mul, b'(') => MulOpen,
+        (MulOpen, b'0'..=b'9') => First(digit(Some(N::default()), b)),
+        (First(a), b'0'..=b'9') => First(digit(a, b)),
+        (First(a), b',') => Comma(a),
+        (Comma(a), b'0'..=b'9') => Second(a, digit(Some(N::default()), b)),
+        (Second(a, n), b'0'..=b'9') => Second(a, digit(n, b)),
+        (Second(a, n), b')') => return Some((Start, Some(Event::Mul(a, n)))),
+        (D, b'o') => Do,
+        (Do, b'(') => DoOpen,
+        (DoOpen, b')') => return Some((Start, Some(Event::Do))),
+        (Do, b'n') => Don,
+        (Don, b'\'') => DonQuote,
+        (DonQuote, b't') => Dont,
+        (Dont, b'(') => DontOpen,
+        (DontOpen, b')') => return Some((Start, Some(Event::Dont))),
+        _ => return None,
+    };
+    Some((next, None))
+}
+
+/// Answers to both parts, computed in a single pass. Each part fails on its
+/// own first overflow, as [`crate::Program::eval_p1`] and
+/// [`crate::Program::eval_p2`] do, so an overflow in part 1 doesn't stop
+/// part 2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totals<N> {
+    pub part1: Result<N, OverflowError>,
+    pub part2: Result<N, OverflowError>,
+}
+
+impl<N: Number> Default for Totals<N> {
+    fn default() -> Self {
+        Self {
+            part1: Ok(N::default()),
+            part2: Ok(N::default()),
+        }
+    }
+}
+
+/// Incremental evaluator that can be fed input in pieces of any size.
+#[derive(Debug, Clone)]
+pub struct StreamEvaluator<N> {
+    state: State<N>,
+    /// Byte offset of the next byte to be fed.
+    pos: u64,
+    /// Byte offset of the start of the current partial match.
+    start: u64,
+    enabled: bool,
+    mode: Arithmetic,
+    totals: Totals<N>,
+}
+
+impl<N: Number> Default for StreamEvaluator<N> {
+    fn default() -> Self {
+        Self::new(Arithmetic::default())
+    }
+}
+
+impl<N: Number> StreamEvaluator<N> {
+    pub fn new(mode: Arithmetic) -> Self {
+        Self {
+            state: State::Start,
+            pos: 0,
+            start: 0,
+            enabled: true,
+            mode,
+            totals: Totals::default(),
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) -> anyhow::Result<()> {
+        for &b in bytes {
+            self.feed_byte(b)?;
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    fn feed_byte(&mut self, b: u8) -> anyhow::Result<()> {
+        let state = std::mem::replace(&mut self.state, State::Start);
+        if state == State::Start {
+            self.start = self.pos;
+        }
+        let (next, event) = match transition(state, b) {
+            Some(t) => t,
+            None => {
+                // Restart the match at `b`; see the module docs.
+                self.start = self.pos;
+                transition(State::Start, b).unwrap_or((State::Start, None))
+            }
+        };
+        self.state = next;
+
+        match event {
+            Some(Event::Mul(Some(a), Some(b))) => {
+                let add = |total: &mut Result<N, OverflowError>| {
+                    let Ok(acc) = total else {
+                        return;
+                    };
+                    match a.mul(&b, self.mode).and_then(|p| acc.add(&p, self.mode)) {
+                        Ok(sum) => *acc = sum,
+                        Err(Overflow) => {
+                            *total = Err(OverflowError {
+                                instruction: format!("mul({a},{b})"),
+                                offset: self.start as usize,
+                            })
+                        }
+                    }
+                };
+                add(&mut self.totals.part1);
+                if self.enabled {
+                    add(&mut self.totals.part2);
+                }
+            }
+            Some(Event::Mul(..)) => {
+                anyhow::bail!("number is too big in mul at byte {}", self.start)
+            }
+            Some(Event::Do) => self.enabled = true,
+            Some(Event::Dont) => self.enabled = false,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// The answers for everything fed so far. A partial instruction at the
+    /// end of the input doesn't count.
+    pub fn totals(&self) -> &Totals<N> {
+        &self.totals
+    }
+}
+
+/// Evaluate everything `reader` produces.
+pub fn eval_reader<N: Number>(
+    mut reader: impl Read,
+    mode: Arithmetic,
+) -> anyhow::Result<Totals<N>> {
+    let mut eval = StreamEvaluator::new(mode);
+    let mut buf = vec![0; CHUNK];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        eval.feed(&buf[..n])?;
+    }
+    Ok(eval.totals)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+
+    use num_bigint::BigInt;
+
+    use super::{eval_reader, StreamEvaluator, Totals};
+    use crate::{num::Arithmetic, Program};
+
+    /// A reader that hands out at most `chunk` bytes per call, so that
+    /// tokens are split across reads.
+    struct Chunked<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.chunk.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_matches_program() -> anyhow::Result<()> {
+        let inputs = [
+            crate::INPUT,
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+            "mmul(1,2)mul(3,mul(4,5)ddon't()do(don't()do()mul(6,7)dodo()mul(1,1)",
+            "mul(1,2mul(3,4)mul(,5)mul(6,)mul(07,008)don'tdo()",
+            // Overflows in part 1 only.
+            "mul(65536,65535)don't()mul(65536,1)",
+            // Overflows in both, at different instructions.
+            "mul(65536,65535)don't()mul(65536,1)do()mul(65536,1)",
+        ];
+        for input in inputs {
+            let program: Program<u32> = input.parse()?;
+            let expected = Totals {
+                part1: program.eval_p1(),
+                part2: program.eval_p2(),
+            };
+            for chunk in [1, 2, 3, 7, 4096] {
+                let reader = Chunked {
+                    data: input.as_bytes(),
+                    chunk,
+                };
+                assert_eq!(
+                    eval_reader(reader, Arithmetic::Checked)?,
+                    expected,
+                    "chunk = {chunk}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> anyhow::Result<()> {
+        let mut eval = StreamEvaluator::<u32>::default();
+        // A number that's too big only matters if the instruction completes.
+        eval.feed(b"mul(99999999999,1]")?;
+        let err = eval.feed(b"xmul(1,99999999999)").unwrap_err();
+        assert!(err.to_string().contains("byte 19"), "{err}");
+
+        // A sum that's too big only fails the part it happens in, and only
+        // in checked mode.
+        let input = b"mul(65536,65535)don't()mul(65536,1)";
+        let mut eval = StreamEvaluator::<u32>::new(Arithmetic::Checked);
+        eval.feed(input)?;
+        assert_eq!(
+            eval.totals().part1.as_ref().unwrap_err().to_string(),
+            "arithmetic overflow in mul(65536,1) at byte 23"
+        );
+        assert_eq!(eval.totals().part2, Ok(65536 * 65535));
+        let mut eval = StreamEvaluator::<u32>::new(Arithmetic::Wrapping);
+        eval.feed(input)?;
+        assert_eq!(
+            eval.totals(),
+            &Totals {
+                part1: Ok(0),
+                part2: Ok(65536 * 65535)
+            }
+        );
+
+        let mut eval = StreamEvaluator::<BigInt>::default();
+        eval.feed(b"mul(99999999999,99999999999)mul(1,1)")?;
+        assert_eq!(
+            eval.totals().part1,
+            Ok(BigInt::from(99999999999u64) * 99999999999u64 + 1)
+        );
+        Ok(())
+    }
+}
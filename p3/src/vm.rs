@@ -0,0 +1,309 @@
+use std::fmt;
+
+use crate::num::{Arithmetic, Number, Overflow};
+
+/// What an opcode does to the machine, given its arguments.
+pub type Exec<N> = fn(&mut Machine<N>, &[N]) -> Result<(), Overflow>;
+
+/// One kind of instruction: `name(arg,arg,...)` with exactly `arity`
+/// arguments.
+pub struct Opcode<N> {
+    pub name: &'static str,
+    pub arity: usize,
+    pub exec: Exec<N>,
+}
+
+// Not derived, since that would needlessly require `N: Copy`.
+impl<N> Clone for Opcode<N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N> Copy for Opcode<N> {}
+
+impl<N> fmt::Debug for Opcode<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.name, self.arity)
+    }
+}
+
+fn mul<N: Number>(m: &mut Machine<N>, args: &[N]) -> Result<(), Overflow> {
+    if m.enabled {
+        let product = args[0].mul(&args[1], m.mode)?;
+        m.acc = m.acc.add(&product, m.mode)?;
+    }
+    Ok(())
+}
+
+impl<N: Number> Opcode<N> {
+    pub const MUL: Self = Self {
+        name: "mul",
+        arity: 2,
+        exec: mul,
+    };
+
+    pub const DO: Self = Self {
+        name: "do",
+        arity: 0,
+        exec: |m, _| {
+            m.enabled = true;
+            Ok(())
+        },
+    };
+
+    pub const DONT: Self = Self {
+        name: "don't",
+        arity: 0,
+        exec: |m, _| {
+            m.enabled = false;
+            Ok(())
+        },
+    };
+}
+
+/// The opcodes a machine understands. When more than one could match at the
+/// same position of the input, the one added first wins.
+#[derive(Debug, Clone)]
+pub struct InstructionSet<N> {
+    opcodes: Vec<Opcode<N>>,
+}
+
+impl<N> Default for InstructionSet<N> {
+    fn default() -> Self {
+        Self {
+            opcodes: Vec::new(),
+        }
+    }
+}
+
+impl<N: Number> InstructionSet<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `opcode`, replacing any existing opcode with the same name.
+    pub fn with(mut self, opcode: Opcode<N>) -> Self {
+        match self.opcodes.iter_mut().find(|o| o.name == opcode.name) {
+            Some(existing) => *existing = opcode,
+            None => self.opcodes.push(opcode),
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Opcode<N>> {
+        self.opcodes.iter().find(|o| o.name == name)
+    }
+
+    pub fn opcodes(&self) -> &[Opcode<N>] {
+        &self.opcodes
+    }
+
+    /// Just `mul`.
+    pub fn part1() -> Self {
+        Self::new().with(Opcode::MUL)
+    }
+
+    /// `mul`, plus `do` and `don't` to switch it on and off.
+    pub fn part2() -> Self {
+        Self::part1().with(Opcode::DO).with(Opcode::DONT)
+    }
+}
+
+/// A decoded instruction, and where it was found in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction<N> {
+    pub name: &'static str,
+    pub args: Vec<N>,
+    /// Byte offset of the instruction in the input.
+    pub offset: usize,
+    /// Length of the instruction's text in bytes.
+    pub len: usize,
+}
+
+impl<N: fmt::Display> fmt::Display for Instruction<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self.args.iter().map(N::to_string).collect();
+        write!(f, "{}({})", self.name, args.join(","))
+    }
+}
+
+/// The machine's registers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine<N> {
+    /// Running total of every product computed while enabled.
+    pub acc: N,
+    /// Whether `mul` currently has any effect.
+    pub enabled: bool,
+    /// What to do when a result doesn't fit in `N`.
+    pub mode: Arithmetic,
+}
+
+impl<N: Number> Default for Machine<N> {
+    fn default() -> Self {
+        Self::new(Arithmetic::default())
+    }
+}
+
+impl<N: Number> Machine<N> {
+    pub fn new(mode: Arithmetic) -> Self {
+        Self {
+            acc: N::default(),
+            enabled: true,
+            mode,
+        }
+    }
+
+    /// Execute `instr` if `set` has a matching opcode, returning whether it
+    /// did. Unknown instructions are ignored, like the rest of the corrupted
+    /// memory around them.
+    pub fn step(
+        &mut self,
+        set: &InstructionSet<N>,
+        instr: &Instruction<N>,
+    ) -> Result<bool, OverflowError> {
+        match set.get(instr.name) {
+            Some(op) if op.arity == instr.args.len() => {
+                (op.exec)(self, &instr.args).map_err(|Overflow| OverflowError {
+                    instruction: instr.to_string(),
+                    offset: instr.offset,
+                })?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+/// An instruction whose result didn't fit in the machine's numeric type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    pub instruction: String,
+    /// Byte offset of the instruction in the input.
+    pub offset: usize,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "arithmetic overflow in {} at byte {}",
+            self.instruction, self.offset
+        )
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+/// The effect of a single instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<'a, N> {
+    pub instruction: &'a Instruction<N>,
+    pub executed: bool,
+    pub before: Machine<N>,
+    pub after: Machine<N>,
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use super::{InstructionSet, Machine, Opcode};
+    use crate::{num::Arithmetic, num::Number, Program};
+
+    #[test]
+    fn test_extended_set() -> anyhow::Result<()> {
+        // A variant with addition, subtraction and a toggle that flips the
+        // current state.
+        let set = InstructionSet::<u32>::part2()
+            .with(Opcode {
+                name: "add",
+                arity: 3,
+                exec: |m, args| {
+                    for arg in args {
+                        m.acc = m.acc.add(arg, m.mode)?;
+                    }
+                    Ok(())
+                },
+            })
+            .with(Opcode {
+                name: "sub",
+                arity: 1,
+                exec: |m, args| {
+                    m.acc = m.acc.sub(&args[0], m.mode)?;
+                    Ok(())
+                },
+            })
+            .with(Opcode {
+                name: "flip",
+                arity: 0,
+                exec: |m, _| {
+                    m.enabled = !m.enabled;
+                    Ok(())
+                },
+            });
+
+        let program = Program::parse_with("add(1,2,3)mul(2,3)sub(1)flip()mul(9,9)add(1,2)", &set)?;
+        let trace = program.trace(&set).collect::<Result<Vec<_>, _>>()?;
+        let names: Vec<String> = trace.iter().map(|s| s.instruction.to_string()).collect();
+        assert_eq!(
+            names,
+            ["add(1,2,3)", "mul(2,3)", "sub(1)", "flip()", "mul(9,9)"]
+        );
+        assert_eq!(trace[1].before.acc, 6);
+        assert_eq!(trace[1].after.acc, 12);
+        assert_eq!(
+            trace.last().unwrap().after,
+            Machine {
+                acc: 11,
+                enabled: false,
+                mode: Arithmetic::Checked,
+            }
+        );
+
+        // Without `don't`, the machine skips it rather than failing.
+        let program: Program<u32> =
+            Program::parse_with("don't()mul(2,2)", &InstructionSet::part2())?;
+        let set = InstructionSet::part1();
+        let trace = program.trace(&set).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(trace[0].instruction.name, Opcode::<u32>::DONT.name);
+        assert!(!trace[0].executed);
+        assert_eq!(trace[1].after.acc, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> anyhow::Result<()> {
+        let input = "mul(65536,65535)xmul(1,1)mul(65536,1)";
+
+        let program: Program<u32> = input.parse()?;
+        let err = program.eval_p1().unwrap_err();
+        assert_eq!(err.offset, 25);
+        assert_eq!(
+            err.to_string(),
+            "arithmetic overflow in mul(65536,1) at byte 25"
+        );
+        let acc = program
+            .run(&InstructionSet::part1(), Arithmetic::Wrapping)?
+            .acc;
+        assert_eq!(acc, 1);
+
+        let expected = 65536 * 65535 + 1 + 65536;
+        assert_eq!(input.parse::<Program<u64>>()?.eval_p1()?, expected);
+        assert_eq!(
+            input.parse::<Program<u128>>()?.eval_p1()?,
+            u128::from(expected)
+        );
+        assert_eq!(
+            input.parse::<Program<BigInt>>()?.eval_p1()?,
+            BigInt::from(expected)
+        );
+
+        // Only arbitrary precision copes with numbers this big.
+        let input = "mul(99999999999999999999999999999999999999999,10)";
+        assert!(input.parse::<Program<u128>>().is_err());
+        let big = BigInt::from_digits("999999999999999999999999999999999999999990").unwrap();
+        assert_eq!(input.parse::<Program<BigInt>>()?.eval_p1()?, big);
+        Ok(())
+    }
+}
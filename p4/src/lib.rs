@@ -0,0 +1,101 @@
+use common::{
+    grid::{Coord, Delta, Grid},
+    solution::Solution,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = MyGrid;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        Ok(s.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(count_xmas(input))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(count_x_mas(input))
+    }
+}
+
+fn count_xmas(grid: &MyGrid) -> u32 {
+    let xmas = [Letter::X, Letter::M, Letter::A, Letter::S];
+
+    let mut count = 0;
+    for coord in grid.iter_coords() {
+        for dir in Delta::directions() {
+            if matches(grid, coord, &xmas, dir) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn count_x_mas(grid: &MyGrid) -> u32 {
+    let mut count = 0;
+    for coord in grid.iter_coords() {
+        if is_center_of_xmas(grid, coord) {
+            count += 1;
+        }
+    }
+    count
+}
+
+pub type MyGrid = Grid<Letter>;
+
+fn matches(grid: &MyGrid, coord: Coord, word: &[Letter], delta: Delta) -> bool {
+    grid.ray(coord, delta)
+        .map(|(_, letter)| letter)
+        .take(word.len())
+        .eq(word)
+}
+
+fn is_center_of_xmas(grid: &MyGrid, coord: Coord) -> bool {
+    if grid.at(coord) != Some(&Letter::A) {
+        return false;
+    }
+
+    let sam = [Letter::S, Letter::A, Letter::M];
+    let mas = [Letter::M, Letter::A, Letter::S];
+
+    for dir in [Delta(-1, -1), Delta(-1, 1)] {
+        let offset = coord.offset(dir);
+        let inv = dir.invert();
+        if !matches(grid, offset, &sam, inv) && !matches(grid, offset, &mas, inv) {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Letter {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Letter {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'X' => Ok(Self::X),
+            'M' => Ok(Self::M),
+            'A' => Ok(Self::A),
+            'S' => Ok(Self::S),
+            c => Err(anyhow::anyhow!("bad letter: {c}")),
+        }
+    }
+}
+
+common::example_tests!(Day4, include_str!("../README.md"));
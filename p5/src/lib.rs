@@ -0,0 +1,114 @@
+use std::{cmp::Ordering, collections::HashSet, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = Problem;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        s.parse()
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.p1())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.p2())
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    edges: HashSet<(u32, u32)>,
+    lists: Vec<Vec<u32>>,
+}
+
+impl Problem {
+    fn p1(&self) -> u32 {
+        self.lists
+            .iter()
+            .filter_map(|list| {
+                if self.satisfies_constraints(list) {
+                    Some(list[list.len() / 2])
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    fn p2(&self) -> u32 {
+        self.lists
+            .iter()
+            .filter_map(|list| {
+                if self.satisfies_constraints(list) {
+                    return None;
+                }
+
+                let mut list = list.clone();
+                list.sort_by(|&a, &b| {
+                    if self.edges.contains(&(a, b)) {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Less
+                    }
+                });
+
+                Some(list[list.len() / 2])
+            })
+            .sum()
+    }
+
+    fn satisfies_constraints(&self, list: &[u32]) -> bool {
+        let size = list.len();
+        for i in 0..size {
+            for j in i + 1..size {
+                if self.edges.contains(&(list[j], list[i])) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl FromStr for Problem {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = parse::sections(s);
+        let (Some(rules), Some(updates)) = (sections.next(), sections.next()) else {
+            return Err(ParseError::at(
+                s,
+                s.len()..s.len(),
+                "expected rules and updates separated by a blank line",
+            )
+            .into());
+        };
+        if let Some(extra) = sections.next() {
+            let line = extra.lines().next().unwrap();
+            return Err(line.error_in(line.text, "unexpected third section").into());
+        }
+
+        let edges = rules
+            .lines()
+            .map(|l| l.pair("|"))
+            .collect::<Result<_, _>>()?;
+        let lists = updates
+            .lines()
+            .map(|l| l.list(","))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { edges, lists })
+    }
+}
+
+common::example_tests!(Day5, include_str!("../README.md"));
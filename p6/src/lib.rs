@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+use common::{
+    grid::{CardinalDirection, Coord, DirectionalBitGrid, Grid},
+    parse,
+    solution::Solution,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = Map;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        s.parse()
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(p1(input))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(p2(input))
+    }
+}
+
+fn p1(m: &Map) -> usize {
+    let (trace, _) = m.simulate();
+    trace.positions().len()
+}
+
+fn p2(m: &Map) -> usize {
+    let mut count = 0;
+    for (pos, cell) in m.grid.iter_cells() {
+        if pos == m.guard.position || *cell != MapCell::Empty {
+            continue;
+        }
+
+        let mut cloned = m.clone();
+        *cloned.grid.at_mut(pos).unwrap() = MapCell::Wall;
+        let (_, endcond) = cloned.simulate();
+        if endcond == EndCondition::Loop {
+            count += 1
+        }
+
+        coz::progress!();
+    }
+    count
+}
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<MapCell>,
+    guard: Guard,
+}
+
+impl Map {
+    fn simulate(&self) -> (DirectionalBitGrid, EndCondition) {
+        let mut trace = DirectionalBitGrid::for_grid(&self.grid);
+        trace.insert(self.guard.position, self.guard.direction);
+
+        let mut guard = self.guard.clone();
+        loop {
+            let next = guard.position.offset(guard.direction.into());
+            match self.grid.at(next) {
+                None => return (trace, EndCondition::OffMap),
+                Some(MapCell::Empty) => {
+                    guard.position = next;
+                }
+                Some(MapCell::Wall) => {
+                    guard.direction = guard.direction.rotate_clockwise();
+                }
+            }
+            if !trace.insert(guard.position, guard.direction) {
+                return (trace, EndCondition::Loop);
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum EndCondition {
+    OffMap,
+    Loop,
+}
+
+impl FromStr for Map {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (grid, guards) = Grid::parse_with_markers(s, |c| match c {
+            '^' | '>' | '<' | 'v' => Some((CardinalDirection::try_from(c).ok()?, MapCell::Empty)),
+            _ => None,
+        })?;
+        let [(position, direction)] = guards[..] else {
+            let message = format!("expected one guard in grid, found {}", guards.len());
+            // Point at the second guard, if there is one.
+            let second = parse::lines(s)
+                .flat_map(|line| {
+                    let markers = line.text.match_indices(['^', '>', '<', 'v']);
+                    markers.map(move |(i, _)| (line, i))
+                })
+                .nth(1);
+            match second {
+                Some((line, i)) => return Err(line.error_at(i, message).into()),
+                None => anyhow::bail!(message),
+            }
+        };
+
+        Ok(Map {
+            grid,
+            guard: Guard {
+                position,
+                direction,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MapCell {
+    Empty,
+    Wall,
+}
+
+impl TryFrom<char> for MapCell {
+    type Error = anyhow::Error;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(MapCell::Empty),
+            '#' => Ok(MapCell::Wall),
+            c => anyhow::bail!("invalid character: {c}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Guard {
+    position: Coord,
+    direction: CardinalDirection,
+}
+
+common::example_tests!(Day6, include_str!("../README.md"));
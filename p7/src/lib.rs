@@ -0,0 +1,129 @@
+use std::str::FromStr;
+
+use common::{parse, solution::Solution};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Problem;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        s.parse()
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.p1())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(input.p2())
+    }
+}
+
+pub struct Problem {
+    equations: Vec<Equation>,
+}
+
+impl Problem {
+    fn p1(&self) -> i64 {
+        self.equations
+            .iter()
+            .filter_map(|eq| {
+                if eq.is_solvable_add_mul() {
+                    Some(eq.target)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    fn p2(&self) -> i64 {
+        self.equations
+            .iter()
+            .filter_map(|eq| {
+                if eq.is_solvable_add_mul_concat() {
+                    Some(eq.target)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+impl FromStr for Problem {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let equations: Vec<Equation> = parse::lines(s)
+            .map(|l| {
+                let (target, values) = l.key_values(": ", " ")?;
+                Ok(Equation { target, values })
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { equations })
+    }
+}
+
+struct Equation {
+    target: i64,
+    values: Vec<i64>,
+}
+
+impl Equation {
+    fn is_solvable_add_mul(&self) -> bool {
+        let mut targets = vec![self.target];
+        let mut vals = self.values.clone();
+
+        while let Some(next) = vals.pop() {
+            targets = targets
+                .into_iter()
+                .flat_map(|target| {
+                    if target < next {
+                        return Vec::from([]).into_iter();
+                    }
+                    if target % next == 0 {
+                        Vec::from([target - next, target / next]).into_iter()
+                    } else {
+                        Vec::from([target - next]).into_iter()
+                    }
+                })
+                .collect();
+        }
+        targets.contains(&0)
+    }
+
+    fn is_solvable_add_mul_concat(&self) -> bool {
+        let mut targets = vec![self.target];
+        let mut vals = self.values.clone();
+
+        while let Some(next) = vals.pop() {
+            targets = targets
+                .into_iter()
+                .flat_map(|target| {
+                    if target < next {
+                        return Vec::new().into_iter();
+                    }
+
+                    let mut nums = vec![target - next];
+
+                    if target % next == 0 {
+                        nums.push(target / next)
+                    }
+
+                    if target.to_string().ends_with(&next.to_string()) {
+                        nums.push(target / (10_i64.pow(next.ilog10() + 1)));
+                    }
+
+                    nums.into_iter()
+                })
+                .collect();
+        }
+        targets.contains(&0)
+    }
+}
+
+common::example_tests!(Day7, include_str!("../README.md"));
@@ -0,0 +1,132 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    grid::{BitGrid, Coord, Grid},
+    solution::Solution,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = Input;
+
+    fn parse(s: &str) -> anyhow::Result<Self::Input> {
+        s.parse()
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(p1(input))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl std::fmt::Display> {
+        Ok(p2(input))
+    }
+}
+
+fn p1(input: &Input) -> usize {
+    let locs = input.antenna_positions();
+
+    // Compute antinodes.
+    let mut antinodes = BitGrid::for_grid(&input.0);
+    for coords in locs.values() {
+        for (i, first) in coords.iter().enumerate() {
+            for second in coords[i + 1..].iter() {
+                antinodes.extend(get_antinodes_p1(&input.0, *first, *second))
+            }
+        }
+    }
+    antinodes.len()
+}
+
+fn p2(input: &Input) -> usize {
+    let locs = input.antenna_positions();
+
+    // Compute antinodes.
+    let mut antinodes = BitGrid::for_grid(&input.0);
+    for coords in locs.values() {
+        for (i, first) in coords.iter().enumerate() {
+            for second in coords[i + 1..].iter() {
+                antinodes.extend(get_antinodes_p2(&input.0, *first, *second))
+            }
+        }
+    }
+    antinodes.len()
+}
+
+fn get_antinodes_p1(grid: &Grid<Cell>, a: Coord, b: Coord) -> impl Iterator<Item = Coord> {
+    let first = a + (b - a);
+    let second = b + (a - b);
+
+    let c1 = if grid.in_bounds(first) {
+        Some(first)
+    } else {
+        None
+    };
+
+    let c2 = if grid.in_bounds(second) {
+        Some(second)
+    } else {
+        None
+    };
+
+    c1.into_iter().chain(c2)
+}
+
+fn get_antinodes_p2(grid: &Grid<Cell>, a: Coord, b: Coord) -> impl Iterator<Item = Coord> + '_ {
+    let delta = b - a;
+
+    // Line from a to the edge of the grid in positive direction.
+    let plus = grid.ray(a, delta).map(|(c, _)| c);
+
+    // Line from a to the edge of the grid in negative direction.
+    let minus = grid.ray(a, -delta).skip(1).map(|(c, _)| c);
+
+    plus.chain(minus)
+}
+
+pub struct Input(Grid<Cell>);
+
+impl Input {
+    fn antenna_positions(&self) -> HashMap<char, Vec<Coord>> {
+        let mut locs: HashMap<char, Vec<Coord>> = HashMap::new();
+        for (loc, cell) in self.0.iter_cells() {
+            match cell {
+                Cell::Empty => continue,
+                Cell::Antenna(c) => {
+                    locs.entry(*c).or_default().push(loc);
+                }
+            }
+        }
+        locs
+    }
+}
+
+impl FromStr for Input {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+enum Cell {
+    Empty,
+    Antenna(char),
+}
+
+impl TryFrom<char> for Cell {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' => Ok(Self::Antenna(c)),
+            '.' => Ok(Self::Empty),
+            _ => Err(anyhow::anyhow!("invalid cell {c}")),
+        }
+    }
+}
+
+common::example_tests!(Day8, include_str!("README.md"));
@@ -0,0 +1,22 @@
+use common::solution::Day;
+
+/// Every implemented day, in calendar order.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<p1::Day1>(p1::INPUT),
+        Day::new::<p2::Day2>(p2::INPUT),
+        Day::new::<p3::Day3>(p3::INPUT),
+        Day::new::<p4::Day4>(p4::INPUT),
+        Day::new::<p5::Day5>(p5::INPUT),
+        Day::new::<p6::Day6>(p6::INPUT),
+        Day::new::<p7::Day7>(p7::INPUT),
+        Day::new::<p8::Day8>(p8::INPUT),
+    ]
+}
+
+pub fn get(day: u32) -> anyhow::Result<Day> {
+    all()
+        .into_iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| anyhow::anyhow!("day {day} is not implemented"))
+}
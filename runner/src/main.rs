@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use clap::Parser;
+use common::{
+    answers::{input_hash, AnswerArgs, AnswerStore, Verdict},
+    bench::{self, Baseline, BenchArgs},
+    input::InputArgs,
+    solution::{Day, ErrorRecord, Part, Report},
+};
+
+mod days;
+mod table;
+
+/// Run Advent of Code solutions.
+#[derive(Parser)]
+struct Args {
+    /// Day to run.
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Only run this part of the day.
+    #[arg(long, requires = "day", conflicts_with = "bench")]
+    part: Option<Part>,
+
+    /// Run every implemented day.
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    /// Print one JSON record per part instead of a table.
+    #[arg(long, conflicts_with = "bench")]
+    json: bool,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    answers: AnswerArgs,
+
+    #[command(flatten)]
+    bench: BenchArgs,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let days = match args.day {
+        Some(day) => vec![days::get(day)?],
+        None => days::all(),
+    };
+    if args.bench.bench {
+        return run_bench(&args, &days);
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let answers_path = args.answers.path();
+    let mut store = AnswerStore::load(&answers_path)?;
+
+    // A day that fails doesn't stop the others from running; its error is
+    // reported in its place.
+    let mut results = Vec::new();
+    for day in &days {
+        let result = run_day(&args, day, &parts, &mut store);
+        results.push((day.day, result));
+    }
+
+    if args.json {
+        for (day, result) in &results {
+            match result {
+                Ok((report, _)) => report.print_json()?,
+                Err(e) => ErrorRecord::new(*day, e).print_json()?,
+            }
+        }
+    } else {
+        print_summary(&results);
+    }
+
+    if args.answers.record {
+        store.save(&answers_path)?;
+    }
+
+    let failed = report_failures(&results);
+    let wrong = results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok())
+        .flat_map(|(_, verdicts)| verdicts)
+        .filter(|(_, v)| matches!(v, Verdict::Wrong { .. }))
+        .count();
+    if wrong > 0 {
+        anyhow::bail!("{wrong} answer(s) differ from {}", answers_path.display());
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} day(s) failed");
+    }
+    Ok(())
+}
+
+fn run_day(
+    args: &Args,
+    day: &Day,
+    parts: &[Part],
+    store: &mut AnswerStore,
+) -> anyhow::Result<(Report, Vec<(Part, Verdict)>)> {
+    let input = args.input.load(day.day, day.input)?;
+    let hash = input_hash(&input.text);
+    let report = day.run(&input.text, parts)?;
+    let verdicts = store.check_report(&report, &hash);
+    if args.answers.record {
+        store.record_report(&report, &hash);
+    }
+    Ok((report, verdicts))
+}
+
+/// Print the full error for each day that failed, since the table only has
+/// room for the first line, and return how many there were.
+fn report_failures<T>(results: &[(u32, anyhow::Result<T>)]) -> usize {
+    let mut failed = 0;
+    for (day, result) in results {
+        if let Err(e) = result {
+            eprintln!("day {day}: {e:#}");
+            failed += 1;
+        }
+    }
+    failed
+}
+
+fn status(verdicts: &[(Part, Verdict)]) -> String {
+    let wrong: Vec<String> = verdicts
+        .iter()
+        .filter_map(|(part, v)| match v {
+            Verdict::Wrong { expected } => Some(format!("p{} expected {expected}", part.number())),
+            _ => None,
+        })
+        .collect();
+    if !wrong.is_empty() {
+        format!("WRONG: {}", wrong.join(", "))
+    } else if verdicts.iter().any(|(_, v)| *v == Verdict::New) {
+        "new".into()
+    } else {
+        "ok".into()
+    }
+}
+
+type DayResult = (u32, anyhow::Result<(Report, Vec<(Part, Verdict)>)>);
+
+fn print_summary(results: &[DayResult]) {
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|(day, result)| {
+            let (r, verdicts) = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    let message = e.to_string();
+                    let first_line = message.lines().next().unwrap_or_default();
+                    return vec![
+                        day.to_string(),
+                        "-".into(),
+                        "-".into(),
+                        "-".into(),
+                        format!("ERROR: {first_line}"),
+                    ];
+                }
+            };
+            let answer = |part| r.outcome(part).map_or("-".into(), |o| o.answer.clone());
+            let total = r.parse_time + r.outcomes.iter().map(|o| o.elapsed).sum::<Duration>();
+            vec![
+                r.day.to_string(),
+                answer(Part::One),
+                answer(Part::Two),
+                format!("{total:.2?}"),
+                status(verdicts),
+            ]
+        })
+        .collect();
+    table::print(&["Day", "Part 1", "Part 2", "Time", "Status"], &rows);
+}
+
+fn run_bench(args: &Args, days: &[Day]) -> anyhow::Result<()> {
+    let opts = &args.bench;
+    let baseline = opts.baseline.as_deref().map(Baseline::load).transpose()?;
+
+    let mut results = Baseline::default();
+    let mut failures = Vec::new();
+    for day in days {
+        let bench = args
+            .input
+            .load(day.day, day.input)
+            .and_then(|input| day.bench(&input.text, opts.warmup, opts.iterations));
+        match bench {
+            Ok(bench) => results.days.push(bench),
+            Err(e) => failures.push((day.day, Err::<(), _>(e))),
+        }
+    }
+
+    let mut rows = Vec::new();
+    for day in &results.days {
+        for (&stage, stats) in &day.stages {
+            let change = match baseline.as_ref().and_then(|b| b.get(day.day, stage)) {
+                Some(before) => bench::change_percent(before, stats)
+                    .map_or("n/a".to_string(), |change| format!("{change:+.1}%")),
+                None => String::new(),
+            };
+            rows.push(vec![
+                day.day.to_string(),
+                stage.to_string(),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.stddev),
+                change,
+            ]);
+        }
+    }
+    table::print(
+        &["Day", "Stage", "Mean", "Median", "Std dev", "Change"],
+        &rows,
+    );
+
+    if let Some(path) = &opts.save_baseline {
+        results.save(path)?;
+    }
+    let failed = report_failures(&failures);
+    if failed > 0 {
+        anyhow::bail!("{failed} day(s) failed");
+    }
+    Ok(())
+}
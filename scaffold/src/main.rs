@@ -0,0 +1,167 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Parser;
+
+mod manifest;
+
+/// Create a new day's crate from the template and register it with the
+/// workspace and the runner.
+#[derive(Parser)]
+struct Args {
+    /// Day of the calendar to create.
+    day: u32,
+
+    /// Root of the workspace.
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    root: PathBuf,
+}
+
+/// Files making up a new day's crate, relative to its directory.
+const TEMPLATE: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../template/Cargo.toml.tmpl")),
+    ("README.md", include_str!("../template/README.md.tmpl")),
+    ("src/lib.rs", include_str!("../template/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../template/main.rs.tmpl")),
+    ("src/input.txt", ""),
+];
+
+fn render(template: &str, day: u32) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    std::fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Create `p{day}` under `root`, returning the paths of every file written
+/// or modified. Every file is rendered before any is written, so a manifest
+/// that can't be edited leaves the workspace untouched; if a write fails
+/// part-way, the error lists the files that were already written.
+fn scaffold(root: &Path, day: u32) -> anyhow::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        anyhow::bail!("day must be between 1 and 25, got {day}");
+    }
+    let name = format!("p{day}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        anyhow::bail!("{} already exists", dir.display());
+    }
+
+    let mut edits = Vec::new();
+
+    let workspace = root.join("Cargo.toml");
+    edits.push((
+        workspace.clone(),
+        manifest::add_workspace_member(&read(&workspace)?, &name)?,
+    ));
+
+    let runner = root.join("runner");
+    if runner.exists() {
+        let deps = runner.join("Cargo.toml");
+        let dep = format!("{name} = {{ version = \"0.1.0\", path = \"../{name}\" }}");
+        edits.push((
+            deps.clone(),
+            manifest::insert_day_line(&read(&deps)?, &dep, day)?,
+        ));
+
+        let days = runner.join("src/days.rs");
+        let entry = format!("        Day::new::<{name}::Day{day}>({name}::INPUT),");
+        edits.push((
+            days.clone(),
+            manifest::insert_day_line(&read(&days)?, &entry, day)?,
+        ));
+    }
+
+    let files = TEMPLATE
+        .iter()
+        .map(|(file, template)| (dir.join(file), render(template, day)))
+        .chain(edits);
+    let mut written = Vec::new();
+    for (path, contents) in files {
+        if let Err(e) = write(&path, &contents) {
+            return Err(partially_written(e, &written));
+        }
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn partially_written(e: anyhow::Error, written: &[PathBuf]) -> anyhow::Error {
+    if written.is_empty() {
+        return e;
+    }
+    let paths: Vec<String> = written.iter().map(|p| p.display().to_string()).collect();
+    e.context(format!(
+        "stopped part-way, after writing {}",
+        paths.join(", ")
+    ))
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    for path in scaffold(&args.root, args.day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::scaffold;
+
+    #[test]
+    fn test_scaffold() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("runner/src"))?;
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"common\", \"p1\", \"runner\"]\n",
+        )?;
+        std::fs::write(
+            root.join("runner/Cargo.toml"),
+            "[dependencies]\np1 = { version = \"0.1.0\", path = \"../p1\" }\n",
+        )?;
+        std::fs::write(
+            root.join("runner/src/days.rs"),
+            "    vec![\n        Day::new::<p1::Day1>(p1::INPUT),\n    ]\n",
+        )?;
+
+        let written = scaffold(&root, 9)?;
+        assert_eq!(written.len(), 8);
+
+        let lib = std::fs::read_to_string(root.join("p9/src/lib.rs"))?;
+        assert!(lib.contains("pub struct Day9;"));
+        assert!(lib.contains("const DAY: u32 = 9;"));
+        assert!(!lib.contains("{{day}}"));
+        assert_eq!(std::fs::read_to_string(root.join("p9/src/input.txt"))?, "");
+
+        let workspace = std::fs::read_to_string(root.join("Cargo.toml"))?;
+        assert!(workspace.contains("\"p1\",\n    \"p9\",\n    \"runner\""));
+        let days = std::fs::read_to_string(root.join("runner/src/days.rs"))?;
+        assert!(days.contains("Day::new::<p9::Day9>(p9::INPUT),"));
+
+        // An earlier day goes before the later ones, not at the end.
+        scaffold(&root, 4)?;
+        let days = std::fs::read_to_string(root.join("runner/src/days.rs"))?;
+        assert!(days.contains(
+            "(p1::INPUT),\n        Day::new::<p4::Day4>(p4::INPUT),\n        Day::new::<p9"
+        ));
+        let deps = std::fs::read_to_string(root.join("runner/Cargo.toml"))?;
+        assert!(deps.contains("\"../p1\" }\np4 = { version = \"0.1.0\", path = \"../p4\" }\np9"));
+
+        assert!(scaffold(&root, 9).is_err());
+        assert!(scaffold(&root, 26).is_err());
+
+        std::fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
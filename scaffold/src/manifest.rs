@@ -0,0 +1,124 @@
+//! Textual edits to the files that list every day. These are deliberately
+//! small changes so that the rest of each file is left as it was written.
+
+/// The day number of a crate named `pN`, if it is one.
+fn day_of(name: &str) -> Option<u32> {
+    name.strip_prefix('p')?.parse().ok()
+}
+
+/// Add `member` to the `members` array of a workspace manifest. Day crates
+/// are kept in calendar order; anything else is appended. The array is
+/// rewritten one member per line.
+pub fn add_workspace_member(manifest: &str, member: &str) -> anyhow::Result<String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or_else(|| anyhow::anyhow!("workspace manifest has no members list"))?;
+    let open = start + "members = [".len();
+    let close = open
+        + manifest[open..]
+            .find(']')
+            .ok_or_else(|| anyhow::anyhow!("unterminated members list"))?;
+
+    let mut members: Vec<&str> = manifest[open..close]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&member) {
+        anyhow::bail!("{member} is already a workspace member");
+    }
+
+    let index = match day_of(member) {
+        Some(day) => members
+            .iter()
+            .position(|m| day_of(m).is_some_and(|d| d > day))
+            .or_else(|| {
+                members
+                    .iter()
+                    .rposition(|m| day_of(m).is_some())
+                    .map(|i| i + 1)
+            })
+            .unwrap_or(members.len()),
+        None => members.len(),
+    };
+    members.insert(index, member);
+
+    let list: String = members.iter().map(|m| format!("    \"{m}\",\n")).collect();
+    Ok(format!(
+        "{}\n{list}{}",
+        &manifest[..open],
+        &manifest[close..]
+    ))
+}
+
+/// Insert `line`, which mentions the crate for `day`, among the lines of
+/// `text` that mention other days' crates, keeping those in calendar order.
+pub fn insert_day_line(text: &str, line: &str, day: u32) -> anyhow::Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|l| mentioned_day(l).is_some())
+        .ok_or_else(|| anyhow::anyhow!("nowhere to insert {line:?}"))?;
+    let index = lines
+        .iter()
+        .position(|l| mentioned_day(l).is_some_and(|d| d > day))
+        .unwrap_or(last + 1);
+
+    let mut out: Vec<&str> = lines[..index].to_vec();
+    out.push(line);
+    out.extend(&lines[index..]);
+    Ok(out.join("\n") + "\n")
+}
+
+/// The day whose crate `line` mentions, e.g. 3 for `p3 = { ... }` or
+/// `Day::new::<p3::Day3>(p3::INPUT),`.
+fn mentioned_day(line: &str) -> Option<u32> {
+    let line = line.trim_start();
+    let name = line
+        .strip_prefix("Day::new::<")
+        .unwrap_or(line)
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .next()
+        .unwrap_or("");
+    day_of(name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{add_workspace_member, insert_day_line};
+
+    #[test]
+    fn test_add_workspace_member() -> anyhow::Result<()> {
+        let manifest = "[workspace]\nmembers = [ \"common\",\n    \"p1\",\n     \"p2\", \"p10\",\n    \"runner\",\n]\nresolver = \"2\"\n";
+
+        let updated = add_workspace_member(manifest, "p3")?;
+        assert_eq!(
+            updated,
+            "[workspace]\nmembers = [\n    \"common\",\n    \"p1\",\n    \"p2\",\n    \"p3\",\n    \"p10\",\n    \"runner\",\n]\nresolver = \"2\"\n"
+        );
+        assert!(add_workspace_member(&updated, "p11")?
+            .contains("\"p10\",\n    \"p11\",\n    \"runner\""));
+        assert!(add_workspace_member(&updated, "tools")?.contains("\"runner\",\n    \"tools\",\n]"));
+        assert!(add_workspace_member(&updated, "p3").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_day_line() -> anyhow::Result<()> {
+        let days = "vec![\n    Day::new::<p1::Day1>(p1::INPUT),\n    Day::new::<p4::Day4>(p4::INPUT),\n]\n";
+        let updated = insert_day_line(days, "    Day::new::<p5::Day5>(p5::INPUT),", 5)?;
+        assert!(updated.ends_with("(p4::INPUT),\n    Day::new::<p5::Day5>(p5::INPUT),\n]\n"));
+        let updated = insert_day_line(days, "    Day::new::<p2::Day2>(p2::INPUT),", 2)?;
+        assert!(updated
+            .contains("(p1::INPUT),\n    Day::new::<p2::Day2>(p2::INPUT),\n    Day::new::<p4"));
+
+        let deps =
+            "[dependencies]\ncommon = { path = \"../common\" }\np12 = { path = \"../p12\" }\n";
+        assert_eq!(
+            insert_day_line(deps, "p3 = { path = \"../p3\" }", 3)?,
+            "[dependencies]\ncommon = { path = \"../common\" }\np3 = { path = \"../p3\" }\np12 = { path = \"../p12\" }\n"
+        );
+        assert!(insert_day_line("", "x", 1).is_err());
+        Ok(())
+    }
+}